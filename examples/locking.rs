@@ -1,6 +1,7 @@
 use bevy::{color::palettes::css::*, prelude::*};
 
 use bevy_alt_ui_navigation_lite::prelude::*;
+use bevy_alt_ui_navigation_lite::style::{FocusStyle, StateStyle};
 
 /// This example illustrates how to make a button "lock". To lock the UI, press
 /// 'A' on controller or 'left click' on mouse when the button with the lock is
@@ -21,7 +22,7 @@ fn main() {
             Update,
             (
                 extra_lock_key.before(NavRequestSystem),
-                (print_nav_events, button_system).after(NavRequestSystem),
+                print_nav_events.after(NavRequestSystem),
             ),
         )
         .run();
@@ -39,22 +40,6 @@ fn extra_lock_key(mut requests: EventWriter<NavRequest>, input: Res<ButtonInput<
     }
 }
 
-#[allow(clippy::type_complexity)]
-fn button_system(
-    mut interaction_query: Query<
-        (&Focusable, &mut BackgroundColor),
-        (Changed<Focusable>, With<Button>),
-    >,
-) {
-    for (focus, mut color) in interaction_query.iter_mut() {
-        if let FocusState::Focused = focus.state() {
-            color.0 = ORANGE_RED.into();
-        } else {
-            color.0 = DARK_GRAY.into();
-        }
-    }
-}
-
 #[derive(Resource)]
 struct Images {
     lock: Handle<Image>,
@@ -111,5 +96,9 @@ fn button_bundle(left: Val, bottom: Val) -> impl Bundle {
             ..Default::default()
         },
         BackgroundColor(DARK_GRAY.into()),
+        FocusStyle::new(StateStyle::new().background(DARK_GRAY)).with(
+            FocusState::Focused,
+            StateStyle::new().background(ORANGE_RED),
+        ),
     )
 }
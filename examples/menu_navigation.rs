@@ -5,6 +5,7 @@ use bevy_alt_ui_navigation_lite::{
         DefaultNavigationPlugins, FocusState, Focusable, MenuBuilder, MenuSetting, NavEvent,
         NavRequest, NavRequestSystem,
     },
+    style::{FocusStyle, StateStyle},
     systems::InputMapping,
 };
 
@@ -34,10 +35,7 @@ fn main() {
         .init_resource::<Materials>()
         .insert_resource(Gameui::new())
         .add_systems(Startup, setup)
-        .add_systems(
-            Update,
-            (button_system, handle_nav_events).after(NavRequestSystem),
-        )
+        .add_systems(Update, handle_nav_events.after(NavRequestSystem))
         .run();
 }
 
@@ -73,21 +71,6 @@ impl FromWorld for Materials {
     }
 }
 
-fn button_system(
-    mut interaction_query: Query<(&Focusable, &mut BackgroundColor), Changed<Focusable>>,
-) {
-    for (focus, mut material) in interaction_query.iter_mut() {
-        let color = match focus.state() {
-            FocusState::Focused => ORANGE_RED,
-            FocusState::Active => GOLD,
-            FocusState::Prioritized => GRAY,
-            FocusState::Inert => DARK_GRAY,
-            FocusState::Blocked => ANTIQUE_WHITE,
-        };
-        *material = color.into();
-    }
-}
-
 fn handle_nav_events(
     mut events: EventReader<NavEvent>,
     mut requests: EventWriter<NavRequest>,
@@ -95,8 +78,8 @@ fn handle_nav_events(
 ) {
     use NavRequest::Action;
     for event in events.read() {
-        if let NavEvent::FocusChanged { from, to } = &event {
-            info!("----------\nfrom: {:?}\n  to: {:?}", from, to);
+        if let NavEvent::FocusChanged { from, to, request } = &event {
+            info!("----------\n{request:?}\nfrom: {:?}\n  to: {:?}", from, to);
         }
         match event {
             NavEvent::NoChanges {
@@ -198,9 +181,15 @@ fn setup(
 }
 fn button() -> impl Bundle {
     let pct = Val::Percent;
+    let background = |color: Srgba| StateStyle::new().background(color);
 
     (
         Button,
+        FocusStyle::new(background(DARK_GRAY))
+            .with(FocusState::Focused, background(ORANGE_RED))
+            .with(FocusState::Active, background(GOLD))
+            .with(FocusState::Prioritized, background(GRAY))
+            .with(FocusState::Blocked, background(ANTIQUE_WHITE)),
         Node {
             width: pct(95.),
             height: pct(12.),
@@ -4,6 +4,7 @@ use bevy_alt_ui_navigation_lite::events::{Direction, NavRequest};
 use bevy_alt_ui_navigation_lite::prelude::{
     DefaultNavigationPlugins, FocusState, Focusable, NavRequestSystem,
 };
+use bevy_alt_ui_navigation_lite::style::{FocusStyle, StateStyle};
 use bevy_alt_ui_navigation_lite::systems::InputMapping;
 
 /// This example shows what happens when there is a lot of focusables on screen.
@@ -18,34 +19,10 @@ fn main() {
     App::new()
         .add_plugins((DefaultPlugins, DefaultNavigationPlugins))
         .add_systems(Startup, setup)
-        .add_systems(
-            Update,
-            (
-                non_stop_move.before(NavRequestSystem),
-                button_system.after(NavRequestSystem),
-            ),
-        )
+        .add_systems(Update, non_stop_move.before(NavRequestSystem))
         .run();
 }
 
-#[derive(Component)]
-struct IdleColor(Color);
-
-fn button_system(
-    mut interaction_query: Query<
-        (&Focusable, &mut BackgroundColor, &IdleColor),
-        Changed<Focusable>,
-    >,
-) {
-    for (focusable, mut color, IdleColor(idle_color)) in interaction_query.iter_mut() {
-        if let FocusState::Focused = focusable.state() {
-            color.0 = ORANGE_RED.into();
-        } else {
-            color.0 = *idle_color;
-        }
-    }
-}
-
 struct MyDirection(Direction);
 impl Default for MyDirection {
     fn default() -> Self {
@@ -118,6 +95,9 @@ fn spawn_button(commands: &mut ChildSpawnerCommands, color: Color, max: u32, i:
         },
         BackgroundColor(color),
         Focusable::default(),
-        IdleColor(color),
+        FocusStyle::new(StateStyle::new().background(color)).with(
+            FocusState::Focused,
+            StateStyle::new().background(ORANGE_RED),
+        ),
     ));
 }
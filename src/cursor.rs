@@ -0,0 +1,206 @@
+//! A `bevy_ui` node moving over the focused element.
+//!
+//! Console-style menus often show focus with a frame or an arrow
+//! sliding from one element to the next.
+//! Add the [`FocusCursorPlugin`] to your app and spawn a node
+//! with the [`FocusCursor`] component to get one.
+use bevy::prelude::*;
+
+use crate::{resolve::Focused, NavRequestSystem};
+
+/// A `bevy_ui` node that tracks the rect of the [`Focused`] node.
+///
+/// Each frame, the [`Node`] `left`, `top`, `width` and `height`
+/// of this entity are set to follow the focused node.
+/// When focus changes, the cursor transitions from its current rect
+/// to the newly focused one over [`duration`](Self::duration) seconds,
+/// following the [`ease`](Self::ease) curve.
+///
+/// The cursor's position is relative to its parent,
+/// so it should be a root node with [`PositionType::Absolute`].
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_alt_ui_navigation_lite::cursor::FocusCursor;
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Node {
+///             position_type: PositionType::Absolute,
+///             border: UiRect::all(Val::Px(3.0)),
+///             ..default()
+///         },
+///         BorderColor(Color::WHITE),
+///         FocusCursor::new(0.15).with_padding(Vec2::splat(4.0)),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct FocusCursor {
+    /// How long, in seconds, it takes to move to a newly focused node.
+    pub duration: f32,
+    /// The easing of the transition between two focused nodes.
+    pub ease: EaseFunction,
+    /// Extra space around the focused node, in logical pixels.
+    pub padding: Vec2,
+    target: Option<Entity>,
+    from: Rect,
+    current: Rect,
+    elapsed: f32,
+}
+impl Default for FocusCursor {
+    fn default() -> Self {
+        FocusCursor::new(0.1)
+    }
+}
+impl FocusCursor {
+    /// A cursor taking `duration` seconds to move between focused nodes.
+    pub fn new(duration: f32) -> Self {
+        FocusCursor {
+            duration,
+            ease: EaseFunction::CubicOut,
+            padding: Vec2::ZERO,
+            target: None,
+            from: Rect::default(),
+            current: Rect::default(),
+            elapsed: 0.0,
+        }
+    }
+    /// Set the [`ease`](Self::ease) function.
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+    /// Set the [`padding`](Self::padding).
+    pub fn with_padding(mut self, padding: Vec2) -> Self {
+        self.padding = padding;
+        self
+    }
+    /// The [`Focused`] entity this cursor is moving to, if any.
+    pub fn target(&self) -> Option<Entity> {
+        self.target
+    }
+}
+
+/// Move [`FocusCursor`]s toward the [`Focused`] node.
+///
+/// This uses the layout of the previous frame.
+pub fn move_focus_cursor(
+    time: Res<Time>,
+    focused: Query<(Entity, &ComputedNode, &GlobalTransform), With<Focused>>,
+    mut cursors: Query<(&mut FocusCursor, &mut Node)>,
+) {
+    let Ok((entity, node, transform)) = focused.single() else {
+        return;
+    };
+    let scale = node.inverse_scale_factor();
+    let center = transform.translation().truncate() * scale;
+    let size = node.size() * scale;
+
+    for (mut cursor, mut style) in &mut cursors {
+        let target = Rect::from_center_size(center, size + cursor.padding * 2.0);
+        if cursor.target != Some(entity) {
+            // Start from the target when there was nothing to move from.
+            cursor.from = if cursor.target.is_none() {
+                target
+            } else {
+                cursor.current
+            };
+            cursor.target = Some(entity);
+            cursor.elapsed = 0.0;
+        }
+        cursor.elapsed += time.delta_secs();
+        let progress = if cursor.duration > 0.0 {
+            cursor.ease.sample_clamped(cursor.elapsed / cursor.duration)
+        } else {
+            1.0
+        };
+        let current = Rect {
+            min: cursor.from.min.lerp(target.min, progress),
+            max: cursor.from.max.lerp(target.max, progress),
+        };
+        cursor.current = current;
+
+        let px = |v: f32| Val::Px(v);
+        let (left, top) = (px(current.min.x), px(current.min.y));
+        let (width, height) = (px(current.width()), px(current.height()));
+        if (style.left, style.top, style.width, style.height) != (left, top, width, height) {
+            style.left = left;
+            style.top = top;
+            style.width = width;
+            style.height = height;
+        }
+    }
+}
+
+/// Move [`FocusCursor`] nodes over the [`Focused`] node.
+///
+/// Add it to your app with `.add_plugins(FocusCursorPlugin)`.
+pub struct FocusCursorPlugin;
+impl Plugin for FocusCursorPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<FocusCursor>();
+
+        app.add_systems(Update, move_focus_cursor.after(NavRequestSystem));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn cursor_transition() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        let node = ComputedNode {
+            size: Vec2::new(20.0, 10.0),
+            ..default()
+        };
+        let first = (node, GlobalTransform::from_xyz(10.0, 5.0, 0.0));
+        let first = world.spawn((first, Focused)).id();
+        let second = (node, GlobalTransform::from_xyz(110.0, 5.0, 0.0));
+        let second = world.spawn(second).id();
+        let cursor = FocusCursor::new(1.0).with_ease(EaseFunction::Linear);
+        let cursor = world.spawn((cursor, Node::default())).id();
+
+        let step = |world: &mut World, secs| {
+            let elapsed = Duration::from_secs_f32(secs);
+            world.resource_mut::<Time>().advance_by(elapsed);
+            world.run_system_once(move_focus_cursor).unwrap();
+            let node = world.get::<Node>(cursor).unwrap();
+            (node.left, node.top, node.width, node.height)
+        };
+        let px = Val::Px;
+        // Jumps to the first focused node
+        assert_eq!(
+            step(&mut world, 0.5),
+            (px(0.0), px(0.0), px(20.0), px(10.0))
+        );
+
+        world.entity_mut(first).remove::<Focused>();
+        world.entity_mut(second).insert(Focused);
+        assert_eq!(
+            step(&mut world, 0.5),
+            (px(50.0), px(0.0), px(20.0), px(10.0))
+        );
+        assert_eq!(
+            step(&mut world, 0.5),
+            (px(100.0), px(0.0), px(20.0), px(10.0))
+        );
+        assert_eq!(
+            step(&mut world, 0.5),
+            (px(100.0), px(0.0), px(20.0), px(10.0))
+        );
+        let target = world.get::<FocusCursor>(cursor).unwrap().target();
+        assert_eq!(target, Some(second));
+    }
+}
@@ -0,0 +1,129 @@
+//! Periodically check the navigation tree for configuration mistakes.
+//!
+//! Add the [`NavDiagnosticsPlugin`] to your app to check the navigation
+//! tree at a fixed interval. Issues are collected in the [`NavTreeIssues`]
+//! resource, and newly found ones are reported as [`NavDiagnostic`]s,
+//! respecting [`NavSettings::strict`].
+//!
+//! This catches mistakes that don't prevent navigation, but make it
+//! behave in unspecified ways, such as several prioritized focusables
+//! in the same menu.
+//!
+//! [`NavSettings::strict`]: crate::prelude::NavSettings::strict
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::common_conditions::on_timer;
+
+use crate::events::NavDiagnostic;
+use crate::menu::{MenuBuilder, MenuSetting};
+use crate::resolve::{in_cycle, ChildQueries, FocusState, Focusable, NavDiagnostics, TreeMenu};
+
+/// The issues found in the navigation tree during the last check.
+///
+/// This is updated by the [`NavDiagnosticsPlugin`].
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct NavTreeIssues {
+    issues: Vec<NavDiagnostic>,
+}
+impl NavTreeIssues {
+    /// All the issues found during the last check.
+    pub fn issues(&self) -> &[NavDiagnostic] {
+        &self.issues
+    }
+    /// Whether the last check didn't find any issue.
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Check the navigation tree and update [`NavTreeIssues`].
+///
+/// Issues not found in the previous check are reported as [`NavDiagnostic`]s.
+#[allow(clippy::type_complexity)]
+pub(crate) fn check_nav_tree(
+    menus: Query<(Entity, Option<&TreeMenu>, Option<&MenuBuilder>), With<MenuSetting>>,
+    focusables: Query<(Entity, &Focusable)>,
+    is_menu: Query<(), With<MenuSetting>>,
+    parents: Query<&ChildOf>,
+    children: ChildQueries,
+    mut diagnostics: NavDiagnostics,
+    mut previous: ResMut<NavTreeIssues>,
+) {
+    let mut issues = Vec::new();
+    let focus_parent = |menu| match menus.get(menu) {
+        Ok((_, Some(tree), _)) => tree.focus_parent,
+        Ok((_, None, Some(MenuBuilder::EntityParent(parent)))) => Some(*parent),
+        _ => None,
+    };
+    let menu_of = |focusable| {
+        parents
+            .iter_ancestors(focusable)
+            .find(|e| is_menu.contains(*e))
+    };
+    let mut roots = Vec::new();
+    for (menu, tree, builder) in &menus {
+        match (tree, builder) {
+            (Some(tree), _) if tree.focus_parent.is_none() => roots.push(menu),
+            (_, Some(MenuBuilder::NamedParent(_))) => {
+                issues.push(NavDiagnostic::UnresolvedName(menu));
+            }
+            _ => {}
+        }
+        if focus_parent(menu).is_some_and(|parent| !focusables.contains(parent)) {
+            issues.push(NavDiagnostic::MissingParent(menu));
+        }
+        let prioritized = children.focusables_of(menu).filter(|e| {
+            let state = focusables.get(*e).map(|(_, f)| f.state());
+            matches!(state, Ok(FocusState::Prioritized))
+        });
+        if prioritized.count() > 1 {
+            issues.push(NavDiagnostic::MultiplePrioritized(menu));
+        }
+        let parent_menu = |menu| focus_parent(menu).and_then(menu_of);
+        if in_cycle(menu, menus.iter().len(), parent_menu) {
+            issues.push(NavDiagnostic::Cycle(menu));
+        }
+    }
+    if roots.len() > 1 {
+        issues.extend(roots.into_iter().map(NavDiagnostic::MultipleRoots));
+    }
+    for (focusable, _) in &focusables {
+        let is_container = |e: &Entity| focusables.contains(*e) || is_menu.contains(*e);
+        let container = parents.iter_ancestors(focusable).find(is_container);
+        if container.is_some_and(|e| focusables.contains(e)) {
+            issues.push(NavDiagnostic::NestedFocusable(focusable));
+        }
+    }
+    for issue in &issues {
+        if !previous.issues.contains(issue) {
+            diagnostics.report(*issue);
+        }
+    }
+    previous.set_if_neq(NavTreeIssues { issues });
+}
+
+/// Check the navigation tree for configuration mistakes every
+/// [`period`](Self::period).
+///
+/// Add it to your app with `.add_plugins(NavDiagnosticsPlugin::default())`.
+/// It requires the bevy [`Time`] resource.
+///
+/// See the [module documentation](self) for details.
+pub struct NavDiagnosticsPlugin {
+    /// How long to wait between two checks, one second by default.
+    pub period: Duration,
+}
+impl Default for NavDiagnosticsPlugin {
+    fn default() -> Self {
+        NavDiagnosticsPlugin {
+            period: Duration::from_secs(1),
+        }
+    }
+}
+impl Plugin for NavDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavTreeIssues>()
+            .add_systems(PostUpdate, check_nav_tree.run_if(on_timer(self.period)));
+    }
+}
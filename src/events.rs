@@ -14,18 +14,26 @@
 //!   See `examples/cursor_navigation` directory for usage clues.
 //! * Output `EventReader<NavEvent>`,
 //!   contains specific information about what the navigation system is doing.
+//! * Output entity-targeted observer events, such as [`FocusGained`] or [`Activated`],
+//!   triggered on the focusables and menus affected by navigation.
+//!   Use them with `EntityCommands::observe` to react to navigation
+//!   on the entity itself rather than filtering [`NavEvent`]s.
 //!
 //! [`Focusable`]: crate::resolve::Focusable
 use bevy::{
     ecs::{
+        component::Component,
         entity::Entity,
         event::EventReader,
+        observer::Trigger,
         query::{QueryData, QueryFilter, ReadOnlyQueryData},
-        system::Query,
+        system::{Commands, In, Query, SystemId},
     },
     math::Vec2,
     prelude::Event,
 };
+use std::fmt;
+
 use non_empty_vec::NonEmpty;
 
 use crate::resolve::LockReason;
@@ -137,6 +145,8 @@ pub enum NavEvent {
     /// - Any `Focusable` in the root menu
     /// - Any `Focusable`
     ///
+    /// Unlike [`NavEvent::FocusChanged`], this isn't caused by a [`NavRequest`].
+    ///
     /// [`Focusable`]: crate::resolve::Focusable
     InitiallyFocused(Entity),
 
@@ -157,6 +167,10 @@ pub enum NavEvent {
         /// The list of active elements from the focused one to the last
         /// active which is affected by the focus change
         from: NonEmpty<Entity>,
+        /// The [`NavRequest`] that caused the focus change.
+        ///
+        /// Focus changes caused by the mouse are [`NavRequest::FocusOn`].
+        request: NavRequest,
     },
 
     /// The [`NavRequest`] didn't lead to any change in focus.
@@ -190,10 +204,15 @@ impl NavEvent {
     /// Create a `FocusChanged` with a single `to`
     ///
     /// Usually the `NavEvent::FocusChanged.to` field has a unique value.
-    pub(crate) fn focus_changed(to: Entity, from: NonEmpty<Entity>) -> NavEvent {
+    pub(crate) fn focus_changed(
+        to: Entity,
+        from: NonEmpty<Entity>,
+        request: NavRequest,
+    ) -> NavEvent {
         NavEvent::FocusChanged {
             from,
             to: NonEmpty::new(to),
+            request,
         }
     }
 
@@ -205,6 +224,297 @@ impl NavEvent {
     }
 }
 
+/// Triggered on a [`Focusable`] when it becomes [`FocusState::Focused`].
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_alt_ui_navigation_lite::prelude::*;
+/// use bevy_alt_ui_navigation_lite::events::FocusGained;
+///
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .spawn((Button, Focusable::new()))
+///         .observe(|trigger: Trigger<FocusGained>| {
+///             info!("{} is now focused", trigger.target());
+///         });
+/// }
+/// ```
+///
+/// [`Focusable`]: crate::resolve::Focusable
+/// [`FocusState::Focused`]: crate::resolve::FocusState::Focused
+#[derive(Debug, Clone, Copy, Event)]
+pub struct FocusGained;
+
+/// Triggered on the previously focused [`Focusable`] when focus changes.
+///
+/// [`Focusable`]: crate::resolve::Focusable
+#[derive(Debug, Clone, Copy, Event)]
+pub struct FocusLost;
+
+/// Triggered on a [`Focusable`] when it becomes [`FocusState::Active`],
+/// meaning that focus went into a menu reachable from it.
+///
+/// [`Focusable`]: crate::resolve::Focusable
+/// [`FocusState::Active`]: crate::resolve::FocusState::Active
+#[derive(Debug, Clone, Copy, Event)]
+pub struct BecameActive;
+
+/// Triggered on the focused [`Focusable`] when it is _activated_.
+///
+/// See [`NavEventReader::activated`] for the meaning of _activated_.
+///
+/// [`Focusable`]: crate::resolve::Focusable
+#[derive(Debug, Clone, Copy, Event)]
+pub struct Activated;
+
+/// Triggered on a [lock focusable] when activating it locks navigation.
+///
+/// [lock focusable]: crate::resolve::Focusable::lock
+#[derive(Debug, Clone, Copy, Event)]
+pub struct Locked;
+
+/// Triggered on a [`TreeMenu`] when focus goes into it.
+///
+/// When several menus are entered at once,
+/// they are entered from the outermost to the innermost one.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_alt_ui_navigation_lite::prelude::*;
+/// use bevy_alt_ui_navigation_lite::events::{MenuEntered, MenuExited};
+///
+/// fn setup(mut commands: Commands) {
+///     let settings = commands.spawn((Button, Focusable::new())).id();
+///     commands
+///         .spawn((Node::default(), MenuSetting::new(), MenuBuilder::EntityParent(settings)))
+///         .observe(|trigger: Trigger<MenuEntered>, mut nodes: Query<&mut Node>| {
+///             nodes.get_mut(trigger.target()).unwrap().display = Display::Flex;
+///         })
+///         .observe(|trigger: Trigger<MenuExited>, mut nodes: Query<&mut Node>| {
+///             nodes.get_mut(trigger.target()).unwrap().display = Display::None;
+///         });
+/// }
+/// ```
+///
+/// [`TreeMenu`]: crate::resolve::TreeMenu
+#[derive(Debug, Clone, Copy, Event)]
+pub struct MenuEntered;
+
+/// Triggered on a [`TreeMenu`] when focus leaves it.
+///
+/// When several menus are exited at once,
+/// they are exited from the innermost to the outermost one.
+///
+/// [`TreeMenu`]: crate::resolve::TreeMenu
+#[derive(Debug, Clone, Copy, Event)]
+pub struct MenuExited;
+
+/// Triggered on the focused [`Focusable`] when a [`NavRequest::Cancel`]
+/// has no menu to go back to.
+///
+/// Useful to show a "quit?" prompt when cancelling from the main menu.
+///
+/// [`Focusable`]: crate::resolve::Focusable
+#[derive(Debug, Clone, Copy, Event)]
+pub struct RootCancelled;
+
+/// Why a [`NavRequest::Move`] couldn't change focus, see [`DeadEnd`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeadEndReason {
+    /// Focus is at the edge of a non-wrapping menu.
+    Edge,
+    /// There is no other focusable to move to, even when wrapping.
+    NoCandidate,
+    /// The focused element is in a [scope menu], which
+    /// doesn't support 2d movement.
+    ///
+    /// [scope menu]: crate::menu::MenuSetting::scope
+    ScopeMenu,
+    /// Navigation is [locked](crate::resolve::NavLock).
+    Locked,
+}
+
+/// Triggered on the focused [`Focusable`] when a [`NavRequest::Move`]
+/// doesn't change focus.
+///
+/// Useful for "bump" animations or sound feedback
+/// when hitting the edge of a menu.
+///
+/// The reason for `Edge` and `NoCandidate` is reported by the
+/// [`MenuNavigationStrategy::try_resolve_2d`] of the navigation plugin.
+///
+/// [`Focusable`]: crate::resolve::Focusable
+/// [`MenuNavigationStrategy::try_resolve_2d`]: crate::resolve::MenuNavigationStrategy::try_resolve_2d
+#[derive(Debug, Clone, Copy, Event)]
+pub struct DeadEnd {
+    /// The requested direction.
+    pub direction: Direction,
+    /// The [`TreeMenu`] containing the focused element, if any.
+    ///
+    /// [`TreeMenu`]: crate::resolve::TreeMenu
+    pub menu: Option<Entity>,
+    /// Why focus didn't change.
+    pub reason: DeadEndReason,
+}
+
+/// Run this system when the entity is [`Activated`].
+///
+/// The system receives the activated entity as [`In<Entity>`].
+/// Register it with `World::register_system` or `Commands::register_system`.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_alt_ui_navigation_lite::prelude::*;
+/// use bevy_alt_ui_navigation_lite::events::OnActivate;
+///
+/// fn start_game(In(button): In<Entity>) {
+///     info!("{button} was pressed, starting the game");
+/// }
+/// fn setup(mut commands: Commands) {
+///     let start_game = commands.register_system(start_game);
+///     commands.spawn((Button, Focusable::new(), OnActivate(start_game)));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Component)]
+pub struct OnActivate(pub SystemId<In<Entity>>);
+
+/// Run this system when the entity gains focus, see [`FocusGained`].
+///
+/// The system receives the focused entity as [`In<Entity>`].
+#[derive(Debug, Clone, Copy, Component)]
+pub struct OnFocus(pub SystemId<In<Entity>>);
+
+/// Run this system when the entity loses focus, see [`FocusLost`].
+///
+/// The system receives the entity that lost focus as [`In<Entity>`].
+#[derive(Debug, Clone, Copy, Component)]
+pub struct OnBlur(pub SystemId<In<Entity>>);
+
+pub(crate) fn run_on_activate(
+    trigger: Trigger<Activated>,
+    callbacks: Query<&OnActivate>,
+    mut commands: Commands,
+) {
+    if let Ok(OnActivate(system)) = callbacks.get(trigger.target()) {
+        commands.run_system_with(*system, trigger.target());
+    }
+}
+
+pub(crate) fn run_on_focus(
+    trigger: Trigger<FocusGained>,
+    callbacks: Query<&OnFocus>,
+    mut commands: Commands,
+) {
+    if let Ok(OnFocus(system)) = callbacks.get(trigger.target()) {
+        commands.run_system_with(*system, trigger.target());
+    }
+}
+
+pub(crate) fn run_on_blur(
+    trigger: Trigger<FocusLost>,
+    callbacks: Query<&OnBlur>,
+    mut commands: Commands,
+) {
+    if let Ok(OnBlur(system)) = callbacks.get(trigger.target()) {
+        commands.run_system_with(*system, trigger.target());
+    }
+}
+
+/// A problem with the navigation tree, caused by how the UI was built.
+///
+/// Those are logged as errors and sent as events,
+/// unless [`NavSettings::strict`] is set, in which case they cause a panic.
+///
+/// [`NavSettings::strict`]: crate::resolve::NavSettings::strict
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Event)]
+#[non_exhaustive]
+pub enum NavDiagnostic {
+    /// This menu is reachable from one of its own focusables,
+    /// directly or through other menus. It is skipped.
+    ///
+    /// Check usages of [`MenuBuilder::EntityParent`] and
+    /// [`MenuBuilder::NamedParent`].
+    ///
+    /// [`MenuBuilder::EntityParent`]: crate::menu::MenuBuilder::EntityParent
+    /// [`MenuBuilder::NamedParent`]: crate::menu::MenuBuilder::NamedParent
+    Cycle(Entity),
+    /// This entity has the [`Focused`] component but no [`Focusable`].
+    ///
+    /// [`Focused`]: crate::resolve::Focused
+    /// [`Focusable`]: crate::resolve::Focusable
+    MissingFocusable(Entity),
+    /// This menu has several [`FocusState::Prioritized`] focusables,
+    /// which one is focused when entering the menu is unspecified.
+    ///
+    /// [`FocusState::Prioritized`]: crate::resolve::FocusState::Prioritized
+    MultiplePrioritized(Entity),
+    /// This [`Focusable`] is a descendant of another `Focusable`
+    /// without a menu in between.
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    NestedFocusable(Entity),
+    /// The [`Focusable`] this menu is reachable from doesn't exist.
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    MissingParent(Entity),
+    /// No [`Focusable`] has the [`Name`] of this menu's
+    /// [`MenuBuilder::NamedParent`].
+    ///
+    /// [`Focusable`]: crate::resolve::Focusable
+    /// [`Name`]: bevy::prelude::Name
+    /// [`MenuBuilder::NamedParent`]: crate::menu::MenuBuilder::NamedParent
+    UnresolvedName(Entity),
+    /// This menu is one of several root menus,
+    /// only one of them is used when picking the initial focus.
+    MultipleRoots(Entity),
+}
+impl fmt::Display for NavDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NavDiagnostic::Cycle(menu) => write!(
+                f,
+                "Navigation graph cycle detected in {menu}, \
+                please check usages of `MenuBuilder::EntityParent/NamedParent`"
+            ),
+            NavDiagnostic::MissingFocusable(entity) => write!(
+                f,
+                "Entity with `Focused` component must also have a `Focusable` component, \
+                {entity} doesn't"
+            ),
+            NavDiagnostic::MultiplePrioritized(menu) => write!(
+                f,
+                "Menu {menu} has more than one prioritized Focusable, \
+                which one is focused when entering it is unspecified"
+            ),
+            NavDiagnostic::NestedFocusable(entity) => write!(
+                f,
+                "Focusable {entity} is nested within another Focusable \
+                without a menu in between"
+            ),
+            NavDiagnostic::MissingParent(menu) => write!(
+                f,
+                "The Focusable menu {menu} is reachable from doesn't exist"
+            ),
+            NavDiagnostic::UnresolvedName(menu) => write!(
+                f,
+                "No Focusable has a Name matching the `MenuBuilder::NamedParent` of menu {menu}"
+            ),
+            NavDiagnostic::MultipleRoots(menu) => write!(
+                f,
+                "Menu {menu} is one of several root menus, \
+                only one of them is used when picking the initial focus"
+            ),
+        }
+    }
+}
+
 /// Extend [`EventReader<NavEvent>`] with methods
 /// to simplify working with [`NavEvent`]s.
 ///
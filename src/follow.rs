@@ -0,0 +1,135 @@
+//! Pan a camera to keep a world-space focused entity on screen.
+//!
+//! Useful when [`Focusable`]s are sprites or 3d objects spread
+//! on a surface larger than the screen, such as strategy maps or skill trees.
+//!
+//! Add the [`FollowFocusedPlugin`] to your app and the [`FollowFocused`]
+//! component to the camera that should follow the [`Focused`] entity.
+//!
+//! [`Focusable`]: crate::prelude::Focusable
+use bevy::prelude::*;
+
+use crate::{resolve::Focused, NavRequestSystem};
+
+/// Pan this camera toward the [`Focused`] entity
+/// when it leaves the [`safe_rect`](Self::safe_rect).
+///
+/// The camera moves parallel to its view plane,
+/// its [`Transform`] is assumed to be relative to the world
+/// (the camera has no parent).
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct FollowFocused {
+    /// The area of the viewport within which the focused entity
+    /// doesn't cause the camera to move.
+    ///
+    /// Coordinates are fractions of the viewport size,
+    /// `(0, 0)` is the top left corner, `(1, 1)` the bottom right one.
+    pub safe_rect: Rect,
+    /// How fast the camera catches up with the focused entity.
+    ///
+    /// Higher is faster, use `f32::INFINITY` to snap immediately.
+    pub speed: f32,
+}
+impl Default for FollowFocused {
+    fn default() -> Self {
+        FollowFocused {
+            safe_rect: Rect::new(0.2, 0.2, 0.8, 0.8),
+            speed: 8.0,
+        }
+    }
+}
+impl FollowFocused {
+    /// Create a [`FollowFocused`] with the given safe rect,
+    /// see [`FollowFocused::safe_rect`].
+    pub fn new(safe_rect: Rect) -> Self {
+        FollowFocused {
+            safe_rect,
+            ..default()
+        }
+    }
+    /// Set the [`speed`](Self::speed).
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+}
+
+/// Move [`FollowFocused`] cameras so that the [`Focused`] entity
+/// goes back within their safe rect.
+pub fn follow_focused(
+    time: Res<Time>,
+    focused: Query<&GlobalTransform, (With<Focused>, Without<FollowFocused>)>,
+    mut cameras: Query<(&FollowFocused, &Camera, &GlobalTransform, &mut Transform)>,
+) {
+    let Ok(focused) = focused.single() else {
+        return;
+    };
+    let target = focused.translation();
+    for (follow, camera, camera_transform, mut transform) in &mut cameras {
+        let Some(viewport_size) = camera.logical_viewport_size() else {
+            continue;
+        };
+        let Ok(on_screen) = camera.world_to_viewport(camera_transform, target) else {
+            continue;
+        };
+        let safe_min = follow.safe_rect.min * viewport_size;
+        let safe_max = follow.safe_rect.max * viewport_size;
+        let clamped = on_screen.clamp(safe_min, safe_max.max(safe_min));
+        if clamped == on_screen {
+            continue;
+        }
+        // Where `target` should be in the world to appear at `clamped`,
+        // at the same depth as it currently is.
+        let Ok(ray) = camera.viewport_to_world(camera_transform, clamped) else {
+            continue;
+        };
+        let plane = InfinitePlane3d::new(camera_transform.forward());
+        let Some(distance) = ray.intersect_plane(target, plane) else {
+            continue;
+        };
+        let offset = target - ray.get_point(distance);
+        transform.translation += offset * catch_up(follow.speed, time.delta_secs());
+    }
+}
+
+/// The fraction of the remaining distance to cover in `delta_secs` at `speed`.
+fn catch_up(speed: f32, delta_secs: f32) -> f32 {
+    // `-inf * 0.0` is NaN, snap even when no time elapsed.
+    if speed.is_infinite() {
+        return 1.0;
+    }
+    (1.0 - (-speed * delta_secs).exp()).clamp(0.0, 1.0)
+}
+
+/// Pan [`FollowFocused`] cameras toward the [`Focused`] entity.
+///
+/// Add it to your app with `.add_plugins(FollowFocusedPlugin)`.
+pub struct FollowFocusedPlugin;
+impl Plugin for FollowFocusedPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<FollowFocused>();
+
+        app.add_systems(Update, follow_focused.after(NavRequestSystem));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catch_up_fraction() {
+        assert_eq!(catch_up(f32::INFINITY, 0.0), 1.0);
+        assert_eq!(catch_up(f32::INFINITY, 0.016), 1.0);
+        assert_eq!(catch_up(8.0, 0.0), 0.0);
+        assert_eq!(catch_up(0.0, 0.016), 0.0);
+
+        let frame = catch_up(8.0, 0.016);
+        assert!(0.0 < frame && frame < 1.0);
+        // Two short frames cover the same distance as one long frame.
+        let two_frames = frame + (1.0 - frame) * frame;
+        assert!((two_frames - catch_up(8.0, 0.032)).abs() < 1e-6);
+    }
+}
@@ -38,11 +38,17 @@
 #![allow(clippy::unnecessary_lazy_evaluations)]
 
 mod commands;
+pub mod cursor;
+pub mod diagnostics;
 pub mod events;
+pub mod follow;
 mod marker;
 pub mod menu;
 mod named;
 mod resolve;
+pub mod scroll;
+pub mod spatial;
+pub mod style;
 pub mod systems;
 
 use std::marker::PhantomData;
@@ -59,7 +65,8 @@ pub mod prelude {
     pub use crate::events::{NavEvent, NavEventReaderExt, NavRequest};
     pub use crate::menu::{MenuBuilder, MenuSetting};
     pub use crate::resolve::{
-        FocusAction, FocusState, Focusable, Focused, MenuNavigationStrategy, NavLock,
+        FocusAction, FocusPath, FocusState, Focusable, Focused, MenuNavigationStrategy, NavLock,
+        NavSettings,
     };
     pub use crate::NavRequestSystem;
     pub use crate::{DefaultNavigationPlugins, NavigationPlugin};
@@ -72,7 +79,7 @@ pub mod mark {
 /// Types useful to define your own custom navigation inputs.
 pub mod custom {
     pub use crate::resolve::UiProjectionQuery;
-    pub use crate::resolve::{Rect, ScreenBoundaries};
+    pub use crate::resolve::{NavDiagnostics, NavTree, Rect, ScreenBoundaries};
     pub use crate::GenericNavigationPlugin;
 }
 
@@ -192,28 +199,68 @@ where
             .register_type::<menu::MenuSetting>()
             .register_type::<resolve::Focusable>()
             .register_type::<resolve::FocusAction>()
+            .register_type::<resolve::FocusPath>()
             .register_type::<resolve::FocusState>()
             .register_type::<resolve::LockReason>()
             .register_type::<resolve::NavLock>()
+            .register_type::<resolve::NavSettings>()
             .register_type::<resolve::Rect>()
             .register_type::<resolve::ScreenBoundaries>()
             .register_type::<resolve::TreeMenu>()
+            .register_type::<style::FocusStyle>()
+            .register_type::<style::StateStyle>()
+            .register_type::<systems::ActionEdge>()
             .register_type::<systems::InputMapping>();
 
         app.add_event::<events::NavRequest>()
             .add_event::<events::NavEvent>()
+            .add_event::<events::NavDiagnostic>()
             .insert_resource(resolve::NavLock::new())
+            .init_resource::<resolve::NavSettings>()
+            .init_resource::<resolve::FocusPath>()
+            .init_resource::<named::NamedFocusables>()
+            .init_resource::<resolve::MenuFocusables>()
+            .init_resource::<spatial::FocusableGrid>()
+            .add_observer(events::run_on_activate)
+            .add_observer(events::run_on_focus)
+            .add_observer(events::run_on_blur)
             .add_systems(
                 Update,
                 (
-                    (resolve::set_first_focused, resolve::consistent_menu),
-                    resolve::listen_nav_requests::<STGY>.in_set(NavRequestSystem),
+                    resolve::update_menu_focusables,
+                    resolve::repair_nav_tree,
+                    (
+                        resolve::set_first_focused,
+                        resolve::consistent_menu,
+                        resolve::adopt_parented_focusables,
+                    ),
+                    ApplyDeferred,
+                    (
+                        // Focusables spawned by systems running before requests.
+                        resolve::update_menu_focusables,
+                        resolve::listen_nav_requests::<STGY>,
+                        ApplyDeferred,
+                    )
+                        .chain()
+                        .in_set(NavRequestSystem),
                 )
                     .chain(),
             )
+            .add_systems(Update, style::apply_focus_style.after(NavRequestSystem))
+            .add_systems(
+                PostUpdate,
+                spatial::update_focusable_grid::<ComputedNode>
+                    .after(bevy::transform::TransformSystem::TransformPropagate),
+            )
             .add_systems(
                 PreUpdate,
-                (named::resolve_named_menus, resolve::insert_tree_menus).chain(),
+                (
+                    resolve::update_menu_focusables,
+                    named::index_named_focusables,
+                    named::resolve_named_menus,
+                    resolve::insert_tree_menus,
+                )
+                    .chain(),
             );
     }
 }
@@ -235,6 +282,7 @@ impl PluginGroup for DefaultNavigationPlugins {
 }
 
 #[cfg(test)]
+#[allow(clippy::drop_non_drop)]
 mod test {
     use crate::prelude::*;
     use bevy::{ecs::event::Event, prelude::*};
@@ -247,17 +295,14 @@ mod test {
     // -> state was modified by users and we didn't expect it
     // -> internal state is not updated correctly to reflect the actual state
     // Consistency design:
-    // - Strong dependency on bevy hierarchy not being mucked with
-    //   (doesn't handle changed parents well)
-    // - Need to get rid of TreeMenu::active_child probably
-    // - Possible to "check and fix" the state in a system that accepts
-    //   Changed<Parent> + RemovedComponent<Focusable | TreeMenu | Parent>
-    // - But the check cannot anticipate when the hierarchy is changed,
-    //   so we are doomed to expose to users inconsistent states
-    //   -> implication: we don't need to maintain it in real time, since
-    //      after certain hierarchy manipulations, it will be inconsistent either way.
-    //      So we could do with only checking and updating when receiving
-    //      NavRequest (sounds like good use case for system chaining)
+    // - `Focusable` component hooks keep `TreeMenu::active_child` up to date
+    //   when focusables are spawned or despawned.
+    // - `repair_nav_tree` checks and fixes the state at the start of the
+    //   `Update` chain, after focusables or menus are removed, or moved in
+    //   the hierarchy directly or through a non-menu container.
+    // - The check cannot anticipate when the hierarchy is changed,
+    //   so between a hierarchy change and the next check, users
+    //   may observe inconsistent states.
 
     /// Define a menu structure to spawn.
     ///
@@ -401,7 +446,7 @@ mod test {
     /// There is nothing beside that that would prevent converting this into a function.
     macro_rules! assert_expected_focus_change {
         ($app:expr, $events:expr, $expected_from:expr, $expected_to:expr $(,)?) => {
-            if let [NavEvent::FocusChanged { to, from }] = $events {
+            if let [NavEvent::FocusChanged { to, from, .. }] = $events {
                 let actual_from = $app.name_list(&*from);
                 assert_eq!(&*actual_from, $expected_from);
 
@@ -432,12 +477,29 @@ mod test {
         let events = world.resource::<Events<E>>();
         events.iter_current_update_events().cloned().collect()
     }
+    /// Observer events logged by [`NavEcsMock::log_trigger`].
+    #[derive(Resource, Default)]
+    struct Triggered(Vec<(&'static str, Entity)>);
 
     /// Wrapper around `App` to make it easier to test the navigation systems.
     struct NavEcsMock {
         app: App,
     }
     impl NavEcsMock {
+        /// Log `E` observer events as `name`, see [`Self::triggered`].
+        fn log_trigger<E: Event>(&mut self, name: &'static str) -> &mut Self {
+            let log = move |trigger: Trigger<E>, mut triggered: ResMut<Triggered>| {
+                triggered.0.push((name, trigger.target()));
+            };
+            self.app.init_resource::<Triggered>().add_observer(log);
+            self
+        }
+        /// The events logged since the last call, and the names of their targets.
+        fn triggered(&mut self) -> (Vec<&'static str>, String) {
+            let triggered = std::mem::take(&mut self.app.world_mut().resource_mut::<Triggered>().0);
+            let (names, entities): (Vec<_>, Vec<_>) = triggered.into_iter().unzip();
+            (names, self.name_list(&entities).join(", "))
+        }
         fn currently_focused(&mut self) -> &str {
             let mut query = self
                 .app
@@ -456,6 +518,36 @@ mod test {
             self.app.update();
             receive_events(self.app.world_mut())
         }
+        fn insert_named(&mut self, name: &str, bundle: impl Bundle) {
+            let mut query = self.app.world_mut().query::<(Entity, &Name)>();
+            let requested = query
+                .iter(self.app.world())
+                .find_map(|(e, n)| (&**n == name).then(|| e))
+                .unwrap();
+            self.app.world_mut().entity_mut(requested).insert(bundle);
+        }
+        fn spawn_in(&mut self, parent: &str, bundle: impl Bundle) {
+            let mut query = self.app.world_mut().query::<(Entity, &Name)>();
+            let parent = query
+                .iter(self.app.world())
+                .find_map(|(e, n)| (&**n == parent).then_some(e))
+                .unwrap();
+            self.app.world_mut().spawn((bundle, ChildOf(parent)));
+            self.app.update();
+        }
+        fn reparent_named(&mut self, child: &str, parent: &str) {
+            let mut query = self.app.world_mut().query::<(Entity, &Name)>();
+            let mut named = |name| {
+                let with_name = |(e, n): (Entity, &Name)| (&**n == name).then_some(e);
+                query.iter(self.app.world()).find_map(with_name).unwrap()
+            };
+            let (child, parent) = (named(child), named(parent));
+            self.app
+                .world_mut()
+                .entity_mut(child)
+                .insert(ChildOf(parent));
+            self.app.update();
+        }
         fn name_list(&mut self, entity_list: &[Entity]) -> Vec<&str> {
             let mut query = self.app.world_mut().query::<&Name>();
             entity_list
@@ -515,6 +607,143 @@ mod test {
         assert_eq!(app.currently_focused(), "Left");
     }
 
+    #[test]
+    fn focus_changed_request() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("LTop"),
+            ]),
+        ]);
+        let requests = |events: Vec<NavEvent>| -> Vec<NavRequest> {
+            let requests = events.into_iter().filter_map(|event| match event {
+                NavEvent::FocusChanged { request, .. } => Some(request),
+                _ => None,
+            });
+            requests.collect()
+        };
+        let left = app.run_focus_on("Left");
+        assert!(matches!(requests(left)[..], [NavRequest::FocusOn(_)]));
+        let action = app.run_request(NavRequest::Action);
+        assert_eq!(requests(action), [NavRequest::Action]);
+        let cancel = app.run_request(NavRequest::Cancel);
+        assert_eq!(requests(cancel), [NavRequest::Cancel]);
+    }
+
+    #[test]
+    fn nav_tree() {
+        use crate::custom::NavTree;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            focusable("Middle"),
+            focusable_to("Left" [
+                prioritized("LTop"),
+                focusable("LBottom"),
+            ]),
+        ]);
+        app.run_focus_on("LBottom");
+        let world = app.app.world_mut();
+        let (path, siblings, parent, child) = world
+            .run_system_once(|tree: NavTree| {
+                let path = tree.focused_path().unwrap();
+                let menu = tree.parent_menu(*path.first()).unwrap();
+                let parent = tree.focus_parent(menu).unwrap();
+                (
+                    path.to_vec(),
+                    tree.siblings(menu),
+                    parent,
+                    tree.child_menu(parent),
+                )
+            })
+            .unwrap();
+        assert_eq!(app.name_list(&path), ["LBottom", "Left"]);
+        assert_eq!(app.name_list(&siblings), ["LTop", "LBottom"]);
+        assert_eq!(app.name_list(&[parent]), ["Left"]);
+        assert_eq!(app.name_list(&[child.unwrap()]), ["Left Menu"]);
+    }
+
+    // The cached focusables of menus follow hierarchy and state changes
+    #[test]
+    fn menu_focusables_cache() {
+        use crate::custom::NavTree;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                prioritized("LTop"),
+                focusable("LBottom"),
+            ]),
+        ]);
+        let siblings = |app: &mut NavEcsMock, menu: &'static str| {
+            let siblings = move |tree: NavTree, names: Query<(Entity, &Name)>| {
+                let menu = names.iter().find(|(_, n)| &***n == menu).unwrap().0;
+                tree.siblings(menu)
+            };
+            let siblings = app.app.world_mut().run_system_once(siblings).unwrap();
+            let names = app.name_list(&siblings);
+            names.into_iter().map(str::to_owned).collect::<Vec<_>>()
+        };
+        app.spawn_in("Left Menu", (Name::new("LLate"), Focusable::new()));
+        assert_eq!(
+            siblings(&mut app, "Left Menu"),
+            ["LTop", "LBottom", "LLate"]
+        );
+
+        app.insert_named("LBottom", Focusable::new().blocked());
+        app.app.update();
+        assert_eq!(siblings(&mut app, "Left Menu"), ["LTop", "LLate"]);
+
+        app.reparent_named("LLate", "Root");
+        assert_eq!(siblings(&mut app, "Left Menu"), ["LTop"]);
+        assert_eq!(siblings(&mut app, "Root"), ["Initial", "Left", "LLate"]);
+    }
+
+    #[test]
+    fn focus_path_resource() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("LTop"),
+            ]),
+        ]);
+        let focus_path = |app: &mut NavEcsMock| {
+            let path = app.app.world().resource::<FocusPath>().clone();
+            let names = app.name_list(path.path()).join(", ");
+            (names, app.name_list(path.menus()).join(", "))
+        };
+        let expected = ("Initial".into(), "Root".into());
+        assert_eq!(focus_path(&mut app), expected);
+        app.run_focus_on("LTop");
+        let expected = ("LTop, Left".into(), "Left Menu, Root".into());
+        assert_eq!(focus_path(&mut app), expected);
+        app.run_request(NavRequest::Cancel);
+        let expected = ("Left".into(), "Root".into());
+        assert_eq!(focus_path(&mut app), expected);
+    }
+
+    #[test]
+    fn focused_marker_is_consistent() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            focusable("Initial"),
+            focusable_to("Left" [
+                focusable("LTop"),
+            ]),
+        ]);
+        fn check(focusables: Query<(&Focusable, Has<Focused>)>) {
+            for (focusable, has_focused) in &focusables {
+                assert_eq!(focusable.state() == FocusState::Focused, has_focused);
+            }
+        }
+        app.app.add_systems(Update, check.after(NavRequestSystem));
+        app.run_focus_on("LTop");
+        app.run_request(NavRequest::Cancel);
+        app.kill_named("Left");
+        app.run_focus_on("Initial");
+        assert_eq!(app.currently_focused(), "Initial");
+    }
+
     #[test]
     fn deep_initial_focusable() {
         let mut app = NavEcsMock::new(spawn_hierarchy![
@@ -601,6 +830,371 @@ mod test {
         );
     }
 
+    #[test]
+    fn skip_hidden_focusables() {
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("Initial"),
+            focusable("Hidden"),
+            focusable("Visible"),
+        ]));
+        app.app.insert_resource(NavSettings {
+            skip_hidden: true,
+            ..default()
+        });
+        app.insert_named("Hidden", InheritedVisibility::HIDDEN);
+        app.insert_named("Visible", InheritedVisibility::VISIBLE);
+        app.kill_named("Initial");
+        assert_eq!(app.currently_focused(), "Visible");
+    }
+
+    // Entering a menu skips its active child once it is hidden
+    #[test]
+    fn skip_hidden_active_child() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                prioritized("LTop"),
+                focusable("LBottom"),
+            ]),
+        ]);
+        app.app.insert_resource(NavSettings {
+            skip_hidden: true,
+            ..default()
+        });
+        app.insert_named("LTop", InheritedVisibility::HIDDEN);
+        app.run_focus_on("Left");
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["LBottom", "Left"]);
+    }
+
+    #[test]
+    fn skip_undisplayed_focusables() {
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("Initial"),
+            focusable("Undisplayed"),
+            focusable("Visible"),
+        ]));
+        app.app.insert_resource(NavSettings {
+            skip_hidden: true,
+            ..default()
+        });
+        let panel = Node {
+            display: Display::None,
+            ..default()
+        };
+        app.app.world_mut().spawn((Name::new("Panel"), panel));
+        app.reparent_named("Undisplayed", "Panel");
+        app.insert_named("Visible", InheritedVisibility::VISIBLE);
+        app.kill_named("Initial");
+        assert_eq!(app.currently_focused(), "Visible");
+    }
+
+    #[test]
+    fn observer_events() {
+        use crate::events::{Activated, BecameActive, FocusGained, FocusLost};
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                prioritized("LTop"),
+                focusable("LBottom"),
+            ]),
+        ]);
+        app.log_trigger::<FocusGained>("gained")
+            .log_trigger::<FocusLost>("lost")
+            .log_trigger::<BecameActive>("active")
+            .log_trigger::<Activated>("activated");
+
+        app.run_focus_on("LBottom");
+        let (events, targets) = app.triggered();
+        assert_eq!(events, ["lost", "gained", "active"]);
+        assert_eq!(targets, "Initial, LBottom, Left");
+
+        app.run_focus_on("LTop");
+        let (events, targets) = app.triggered();
+        assert_eq!(events, ["lost", "gained"]);
+        assert_eq!(targets, "LBottom, LTop");
+
+        app.run_request(NavRequest::Action);
+        let (events, targets) = app.triggered();
+        assert_eq!(events, ["activated"]);
+        assert_eq!(targets, "LTop");
+
+        app.run_focus_on("Left");
+        let (events, targets) = app.triggered();
+        assert_eq!(events, ["lost", "gained"]);
+        assert_eq!(targets, "LTop, Left");
+
+        // Entering a menu activates the focusable it was entered from
+        app.run_request(NavRequest::Action);
+        let (events, targets) = app.triggered();
+        assert_eq!(events, ["lost", "gained", "active"]);
+        assert_eq!(targets, "Left, LTop, Left");
+    }
+
+    #[test]
+    fn menu_events() {
+        use crate::events::{MenuEntered, MenuExited, RootCancelled};
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable_to("LTop" [
+                    focusable("LTopForward"),
+                ]),
+                focusable("LBottom"),
+            ]),
+            focusable_to("Right" [
+                focusable("RTop"),
+            ]),
+        ]);
+        app.log_trigger::<MenuEntered>("entered")
+            .log_trigger::<MenuExited>("exited")
+            .log_trigger::<RootCancelled>("root cancel");
+
+        app.run_focus_on("LTopForward");
+        let (events, targets) = app.triggered();
+        assert_eq!(events, ["entered", "entered"]);
+        assert_eq!(targets, "Left Menu, LTop Menu");
+
+        app.run_focus_on("RTop");
+        let (events, targets) = app.triggered();
+        assert_eq!(events, ["exited", "exited", "entered"]);
+        assert_eq!(targets, "LTop Menu, Left Menu, Right Menu");
+
+        app.run_request(NavRequest::Cancel);
+        let (events, targets) = app.triggered();
+        assert_eq!(events, ["exited"]);
+        assert_eq!(targets, "Right Menu");
+
+        app.run_request(NavRequest::Cancel);
+        let (events, targets) = app.triggered();
+        assert_eq!(events, ["root cancel"]);
+        assert_eq!(targets, "Right");
+    }
+
+    #[test]
+    fn dead_end_events() {
+        use crate::events::{DeadEnd, DeadEndReason, Direction};
+
+        #[derive(Resource, Default)]
+        struct DeadEnds(Vec<(DeadEnd, Entity)>);
+        let mut app =
+            NavEcsMock::new(spawn_hierarchy![prioritized("Initial"), focusable("Other"),]);
+        app.app.init_resource::<DeadEnds>().add_observer(
+            |trigger: Trigger<DeadEnd>, mut dead_ends: ResMut<DeadEnds>| {
+                dead_ends.0.push((*trigger.event(), trigger.target()));
+            },
+        );
+        let dead_ends = |app: &mut NavEcsMock| {
+            let dead_ends = std::mem::take(&mut app.app.world_mut().resource_mut::<DeadEnds>().0);
+            let (events, entities): (Vec<_>, Vec<_>) = dead_ends.into_iter().unzip();
+            let reasons: Vec<_> = events.iter().map(|e| (e.direction, e.reason)).collect();
+            let menus: Vec<_> = events.iter().filter_map(|e| e.menu).collect();
+            let menus = app.name_list(&menus).join(", ");
+            (reasons, app.name_list(&entities).join(", "), menus)
+        };
+
+        app.run_request(NavRequest::Move(Direction::East));
+        let expected = vec![(Direction::East, DeadEndReason::Edge)];
+        assert_eq!(
+            dead_ends(&mut app),
+            (expected, "Initial".into(), "Root".into())
+        );
+
+        app.run_request(NavRequest::Lock);
+        app.run_request(NavRequest::Move(Direction::South));
+        let expected = vec![(Direction::South, DeadEndReason::Locked)];
+        assert_eq!(
+            dead_ends(&mut app),
+            (expected, "Initial".into(), "Root".into())
+        );
+    }
+
+    #[test]
+    fn callback_components() {
+        use crate::events::{OnActivate, OnBlur, OnFocus};
+
+        #[derive(Resource, Default)]
+        struct Called(Vec<(&'static str, Entity)>);
+        fn log(name: &'static str) -> impl Fn(In<Entity>, ResMut<Called>) {
+            move |In(entity), mut called| called.0.push((name, entity))
+        }
+        let mut app = NavEcsMock::new(spawn_hierarchy!(@rootless [
+            prioritized("Initial"),
+            focusable("Button"),
+        ]));
+        let world = app.app.world_mut();
+        world.init_resource::<Called>();
+        let on_activate = world.register_system(log("activate"));
+        let on_focus = world.register_system(log("focus"));
+        let on_blur = world.register_system(log("blur"));
+        app.insert_named("Initial", OnBlur(on_blur));
+        app.insert_named("Button", (OnActivate(on_activate), OnFocus(on_focus)));
+
+        app.run_focus_on("Button");
+        app.run_request(NavRequest::Action);
+        let called = std::mem::take(&mut app.app.world_mut().resource_mut::<Called>().0);
+        let (names, entities): (Vec<_>, Vec<_>) = called.into_iter().unzip();
+        assert_eq!(names, ["blur", "focus", "activate"]);
+        assert_eq!(app.name_list(&entities), ["Initial", "Button", "Button"]);
+    }
+
+    #[test]
+    fn menu_diagnostics() {
+        use crate::events::NavDiagnostic;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![prioritized("Initial")]);
+        let world = app.app.world_mut();
+        let menu = |builder| (MenuSetting::new(), builder);
+        let cycle_a = world.spawn(menu(MenuBuilder::from_named("B1"))).id();
+        let cycle_b = world.spawn(menu(MenuBuilder::from_named("A1"))).id();
+        world.spawn((Name::new("A1"), Focusable::new(), ChildOf(cycle_a)));
+        world.spawn((Name::new("B1"), Focusable::new(), ChildOf(cycle_b)));
+        app.app.update();
+
+        let diagnostics = receive_events::<NavDiagnostic>(app.app.world());
+        assert_eq!(
+            diagnostics,
+            [NavDiagnostic::Cycle(cycle_a), NavDiagnostic::Cycle(cycle_b)]
+        );
+        // Diagnostics are only reported once
+        app.app.update();
+        assert_eq!(receive_events::<NavDiagnostic>(app.app.world()), []);
+        assert_eq!(app.currently_focused(), "Initial");
+    }
+
+    // Requests sent when the focused entity stops being focusable
+    // start from a new focusable
+    #[test]
+    fn unfocusable_focused() {
+        use crate::events::NavDiagnostic;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("LA"),
+            ]),
+        ]);
+        app.run_focus_on("Left");
+        let world = app.app.world_mut();
+        let mut focused = world.query_filtered::<Entity, With<Focused>>();
+        let left = focused.single(world).unwrap();
+        world.entity_mut(left).remove::<Focusable>();
+        let events = app.run_request(NavRequest::Action);
+        assert!(
+            matches!(events[..], [_, NavEvent::NoChanges { .. }]),
+            "{events:?}"
+        );
+        assert_eq!(app.currently_focused(), "LA");
+
+        // Manually marked as focused
+        let world = app.app.world_mut();
+        let la = focused.single(world).unwrap();
+        world.entity_mut(la).remove::<Focused>();
+        world.entity_mut(left).insert(Focused);
+        let events = app.run_request(NavRequest::Action);
+        let diagnostics = receive_events::<NavDiagnostic>(app.app.world());
+        assert_eq!(diagnostics, [NavDiagnostic::MissingFocusable(left)]);
+        assert!(
+            matches!(events[..], [NavEvent::NoChanges { .. }]),
+            "{events:?}"
+        );
+    }
+
+    // Cycles created after menus are built are reported when found
+    #[test]
+    fn late_cycle_diagnostics() {
+        use crate::events::NavDiagnostic;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("LA"),
+            ]),
+        ]);
+        let world = app.app.world_mut();
+        let mut names = world.query::<(Entity, &Name)>();
+        let left_menu = names
+            .iter(world)
+            .find_map(|(e, n)| (&**n == "Left Menu").then_some(e));
+        let cycle = [NavDiagnostic::Cycle(left_menu.unwrap())];
+        app.run_focus_on("Left");
+        app.reparent_named("Left", "Left Menu");
+        assert_eq!(receive_events::<NavDiagnostic>(app.app.world()), cycle);
+
+        app.run_focus_on("LA");
+        assert_eq!(receive_events::<NavDiagnostic>(app.app.world()), cycle);
+    }
+
+    #[test]
+    #[should_panic(expected = "Navigation graph cycle detected")]
+    fn strict_menu_diagnostics() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![prioritized("Initial")]);
+        app.app.insert_resource(NavSettings {
+            strict: true,
+            ..default()
+        });
+        let world = app.app.world_mut();
+        let cycle = world.spawn((MenuSetting::new(), MenuBuilder::from_named("A1")));
+        let cycle = cycle.id();
+        world.spawn((Name::new("A1"), Focusable::new(), ChildOf(cycle)));
+        app.app.update();
+    }
+
+    #[test]
+    fn nav_tree_issues() {
+        use crate::diagnostics::{check_nav_tree, NavTreeIssues};
+        use crate::events::NavDiagnostic;
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                prioritized("LTop"),
+                prioritized("LBottom"),
+            ]),
+        ]);
+        app.spawn_in("Initial", (Name::new("Nested"), Focusable::new()));
+        let world = app.app.world_mut();
+        let unresolved = (MenuSetting::new(), MenuBuilder::from_named("Nowhere"));
+        let unresolved = world.spawn(unresolved).id();
+        let second_root = world.spawn((MenuSetting::new(), MenuBuilder::Root)).id();
+        world.spawn((Name::new("Other"), Focusable::new(), ChildOf(second_root)));
+        app.app.update();
+
+        let world = app.app.world_mut();
+        let mut query = world.query::<(Entity, &Name)>();
+        let mut named = |name| {
+            let with_name = |(e, n): (Entity, &Name)| (&**n == name).then_some(e);
+            query.iter(world).find_map(with_name).unwrap()
+        };
+        let (root, left_menu) = (named("Root"), named("Left Menu"));
+        let nested = named("Nested");
+        world.resource_mut::<Events<NavDiagnostic>>().clear();
+        world.init_resource::<NavTreeIssues>();
+        world.run_system_once(check_nav_tree).unwrap();
+
+        let mut issues = world.resource::<NavTreeIssues>().issues().to_vec();
+        issues.sort_by_key(|issue| format!("{issue:?}"));
+        let mut expected = [
+            NavDiagnostic::MultiplePrioritized(left_menu),
+            NavDiagnostic::NestedFocusable(nested),
+            NavDiagnostic::UnresolvedName(unresolved),
+            NavDiagnostic::MultipleRoots(root),
+            NavDiagnostic::MultipleRoots(second_root),
+        ];
+        expected.sort_by_key(|issue| format!("{issue:?}"));
+        assert_eq!(issues, expected);
+        assert_eq!(receive_events::<NavDiagnostic>(world).len(), 5);
+
+        // Issues are only reported when they first appear
+        world.resource_mut::<Events<NavDiagnostic>>().clear();
+        world.run_system_once(check_nav_tree).unwrap();
+        assert_eq!(receive_events::<NavDiagnostic>(world), []);
+        assert_eq!(world.resource::<NavTreeIssues>().issues().len(), 5);
+    }
+
     // ====
     // What happens when Focused element is killed
     // ====
@@ -650,20 +1244,301 @@ mod test {
         assert_eq!(app.currently_focused(), "Antony");
     }
 
+    // A prioritized focusable spawned in an existing menu becomes its active child
+    #[test]
+    fn late_prioritized_focusable() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                prioritized("LTop"),
+                focusable("LBottom"),
+            ]),
+        ]);
+        let late = (Name::new("LLate"), Focusable::new().prioritized());
+        app.spawn_in("Left Menu", late);
+        assert_eq!(app.state_of("LTop"), FocusState::Inert);
+
+        app.run_focus_on("Left");
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["LLate", "Left"]);
+
+        // Focus is in the menu, the active child shouldn't change.
+        let later = (Name::new("LLater"), Focusable::new().prioritized());
+        app.spawn_in("Left Menu", later);
+        assert_eq!(app.currently_focused(), "LLate");
+        let events = app.run_request(NavRequest::Cancel);
+        assert_expected_focus_change!(app, &events[..], ["LLate", "Left"], ["Left"]);
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["LLate", "Left"]);
+    }
+
+    // Prioritized focusables in a menu being built don't change
+    // the active child of the menu containing it
+    #[test]
+    fn nested_menu_prioritized_focusable() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" [
+                focusable("LA"),
+                prioritized("LB"),
+            ]),
+        ]);
+        let world = app.app.world_mut();
+        let mut names = world.query::<(Entity, &Name)>();
+        let left_menu = names
+            .iter(world)
+            .find_map(|(e, n)| (&**n == "Left Menu").then_some(e));
+        let sub = (MenuSetting::new(), MenuBuilder::from_named("LA"));
+        let sub = world.spawn((Name::new("Sub"), sub, ChildOf(left_menu.unwrap())));
+        let sub = sub.id();
+        world.spawn((Name::new("C"), Focusable::new().prioritized(), ChildOf(sub)));
+        app.app.update();
+        assert_eq!(app.state_of("LB"), FocusState::Prioritized);
+
+        app.run_focus_on("Left");
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["LB", "Left"]);
+        let events = app.run_focus_on("LA");
+        assert_expected_focus_change!(app, &events[..], ["LB"], ["LA"]);
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["LA"], ["C", "LA"]);
+    }
+
+    // Focusables spawned in the same frame as a request are navigable
+    #[test]
+    fn same_frame_focusable() {
+        use crate::events::ScopeDirection;
+
+        let mut app = NavEcsMock::new(spawn_hierarchy![prioritized("Initial")]);
+        app.insert_named("Root", MenuSetting::new().scope());
+        let spawn_late = |mut commands: Commands, root: Query<(Entity, &Name)>| {
+            let root = root.iter().find_map(|(e, n)| (&**n == "Root").then_some(e));
+            let late = (Name::new("Late"), Focusable::new(), ChildOf(root.unwrap()));
+            commands.spawn(late);
+        };
+        let spawn_late =
+            spawn_late.run_if(|mut ran: Local<bool>| !std::mem::replace(&mut *ran, true));
+        app.app
+            .add_systems(Update, spawn_late.before(NavRequestSystem));
+
+        let events = app.run_request(NavRequest::ScopeMove(ScopeDirection::Next));
+        assert_expected_focus_change!(app, &events[..], ["Initial"], ["Late"]);
+    }
+
+    // Menus are resolved once a focusable is given their parent's name
+    #[test]
+    fn late_named_parent() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable("Unnamed"),
+        ]);
+        let world = app.app.world_mut();
+        let menu = (MenuSetting::new(), MenuBuilder::from_named("Late"));
+        let menu = world.spawn(menu).id();
+        world.spawn((Name::new("LateTop"), Focusable::new(), ChildOf(menu)));
+        app.app.update();
+        assert!(app.app.world().get::<MenuBuilder>(menu).is_some());
+
+        app.insert_named("Unnamed", Name::new("Late"));
+        app.app.update();
+        app.run_focus_on("Late");
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Late"], ["LateTop", "Late"]);
+    }
+
+    // Empty menus can't be entered until focusables are spawned in them
+    #[test]
+    fn lazily_populated_menu() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            prioritized("Initial"),
+            focusable_to("Left" []),
+        ]);
+        app.run_focus_on("Left");
+        let events = app.run_request(NavRequest::Action);
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+        assert_eq!(app.currently_focused(), "Left");
+
+        app.spawn_in("Left Menu", (Name::new("LTop"), Focusable::new()));
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["LTop", "Left"]);
+
+        // Going back to empty when its only focusable is removed
+        app.run_focus_on("Initial");
+        app.kill_named("LTop");
+        app.run_focus_on("Left");
+        let events = app.run_request(NavRequest::Action);
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+        app.run_focus_on("Initial");
+
+        // Focusables spawned before being added to the menu
+        let world = app.app.world_mut();
+        let mut names = world.query::<(Entity, &Name)>();
+        let left_menu = names
+            .iter(world)
+            .find_map(|(e, n)| (&**n == "Left Menu").then_some(e));
+        let late = world.spawn((Name::new("LLate"), Focusable::new())).id();
+        world.entity_mut(left_menu.unwrap()).add_child(late);
+        app.app.update();
+        app.run_focus_on("Left");
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["LLate", "Left"]);
+
+        // Prioritized ones replace the active child
+        app.run_focus_on("Initial");
+        let world = app.app.world_mut();
+        let later = (Name::new("LLater"), Focusable::new().prioritized());
+        let later = world.spawn(later).id();
+        world.entity_mut(left_menu.unwrap()).add_child(later);
+        app.app.update();
+        app.run_focus_on("Left");
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["LLater", "Left"]);
+    }
+
     // ====
     // removal of parent menu and focusables
     // ====
 
     // Relink the child menu to the removed parent's parents
+    #[test]
+    fn menu_parent_kill_robust() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            focusable("Initial"),
+            focusable_to("Left" [
+                focusable_to("LTop" [
+                    prioritized("LTopForward"),
+                ]),
+                focusable("LBottom"),
+            ]),
+        ]);
+        app.run_focus_on("LTopForward");
+        app.kill_named("Left Menu");
+        assert_eq!(app.currently_focused(), "LTopForward");
+        assert_eq!(app.state_of("Left"), FocusState::Active);
+
+        let events = app.run_request(NavRequest::Cancel);
+        assert_expected_focus_change!(app, &events[..], ["LTopForward", "Left"], ["Left"]);
+
+        // The relinked menu is reachable from its new parent
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["LTopForward", "Left"]);
+    }
+
     // Make sure this works with root as well
+    #[test]
+    fn root_menu_kill_robust() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            focusable("Initial"),
+            focusable_to("Left" [
+                prioritized("LTop"),
+                focusable("LBottom"),
+            ]),
+        ]);
+        app.run_focus_on("LTop");
+        app.kill_named("Root");
+        assert_eq!(app.currently_focused(), "LTop");
+
+        let events = app.run_request(NavRequest::Cancel);
+        assert!(matches!(events[..], [NavEvent::NoChanges { .. }]));
+        app.run_focus_on("LBottom");
+        assert_eq!(app.currently_focused(), "LBottom");
+    }
+
     // Relink when the focusable parent of a menu is killed
-    // NOTE: user is warned against engaging in such operations, implementation can wait
+    #[test]
+    fn active_kill_robust() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            focusable("Initial"),
+            focusable_to("Left" [
+                focusable_to("LTop" [
+                    prioritized("LTopForward"),
+                ]),
+                focusable("LBottom"),
+            ]),
+        ]);
+        app.run_focus_on("LTopForward");
+        app.kill_named("LTop");
+        assert_eq!(app.currently_focused(), "LTopForward");
+        assert_eq!(app.state_of("LBottom"), FocusState::Prioritized);
+
+        let events = app.run_request(NavRequest::Cancel);
+        assert_expected_focus_change!(app, &events[..], ["LTopForward", "Left"], ["Left"]);
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["LBottom", "Left"]);
+    }
 
     // ====
     // some reparenting potential problems
     // ====
 
     // Focused element is reparented to a new menu
+    #[test]
+    fn focused_reparent_robust() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            focusable_to("Left" [
+                focusable("LTop"),
+                focusable("LBottom"),
+            ]),
+            focusable_to("Right" [
+                focusable("RTop"),
+            ]),
+        ]);
+        app.run_focus_on("LBottom");
+        app.reparent_named("LBottom", "Right Menu");
+        assert_eq!(app.currently_focused(), "LBottom");
+        assert_eq!(app.state_of("Left"), FocusState::Inert);
+        assert_eq!(app.state_of("Right"), FocusState::Active);
+
+        let events = app.run_request(NavRequest::Cancel);
+        assert_expected_focus_change!(app, &events[..], ["LBottom", "Right"], ["Right"]);
+        let events = app.run_focus_on("Left");
+        assert_expected_focus_change!(app, &events[..], ["Right"], ["Left"]);
+        let events = app.run_request(NavRequest::Action);
+        assert_expected_focus_change!(app, &events[..], ["Left"], ["LTop", "Left"]);
+    }
+
     // Active element is reparented to a new menu
-    // NOTE: those are not expected to work. Currently considered a user error.
+    #[test]
+    fn active_reparent_robust() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            focusable("Initial"),
+            focusable_to("Left" [
+                focusable_to("LTop" [
+                    prioritized("LTopForward"),
+                ]),
+            ]),
+            focusable_to("Right" [
+                focusable("RTop"),
+            ]),
+        ]);
+        app.run_focus_on("LTopForward");
+        app.reparent_named("LTop", "Right Menu");
+        assert_eq!(app.currently_focused(), "LTopForward");
+        assert_eq!(app.state_of("LTop"), FocusState::Active);
+        assert_eq!(app.state_of("Right"), FocusState::Active);
+        assert_eq!(app.state_of("Left"), FocusState::Inert);
+    }
+
+    // Non-menu container of the focused element is reparented to a new menu
+    #[test]
+    fn container_reparent_robust() {
+        let mut app = NavEcsMock::new(spawn_hierarchy![
+            focusable_to("Left" [
+                focusable("LTop"),
+            ]),
+            focusable_to("Right" [
+                focusable("RTop"),
+            ]),
+        ]);
+        app.spawn_in("Left Menu", Name::new("Panel"));
+        app.spawn_in("Panel", (Name::new("LPanel"), Focusable::new()));
+        app.run_focus_on("LPanel");
+        app.reparent_named("Panel", "Right Menu");
+        assert_eq!(app.currently_focused(), "LPanel");
+        assert_eq!(app.state_of("Left"), FocusState::Inert);
+        assert_eq!(app.state_of("Right"), FocusState::Active);
+
+        let events = app.run_request(NavRequest::Cancel);
+        assert_expected_focus_change!(app, &events[..], ["LPanel", "Right"], ["Right"]);
+    }
 }
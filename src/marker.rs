@@ -20,7 +20,7 @@ pub(crate) fn mark_new_menus<T: Component + Clone>(
     for (new_menu, marker) in new_markers.iter() {
         let repeat_marker = iter::repeat((marker.0.clone(),));
         let menu_children = children.focusables_of(new_menu);
-        to_insert.extend(menu_children.into_iter().zip(repeat_marker));
+        to_insert.extend(menu_children.zip(repeat_marker));
     }
     cmds.insert_batch(to_insert);
 }
@@ -37,16 +37,15 @@ pub enum MenuBuilder {
     ///
     /// # Performance and edge cases
     ///
-    /// `bevy-ui-navigation` tries to convert **each frame** every
-    /// `MenuBuilder::NamedParent` into a [`MenuBuilder::EntityParent`].
+    /// `bevy-ui-navigation` keeps an index of [`Focusable`]s by [`Name`],
+    /// and converts `MenuBuilder::NamedParent` into a [`MenuBuilder::EntityParent`]
+    /// when a named focusable is added or renamed, or a `MenuBuilder` changes.
+    /// Looking up a name in the index is cheap.
     ///
-    /// It iterates every [`Focusable`] with a [`Name`] component until it finds
-    /// a match. And repeat the operation next frame if no match is found.
+    /// If several focusables have the same name, the first one added is used.
     ///
-    /// This incurs a significant performance cost per unmatched `NamedParent`!
     /// `bevy-ui-navigation` emits a **`WARN`** per second if it encounters
-    /// unmatched `NamedParent`s. Pay attention to this message if you don't
-    /// want to waste preciously CPU cycles.
+    /// unmatched `NamedParent`s.
     ///
     /// [`Focusable`]: crate::prelude::Focusable
     NamedParent(Name),
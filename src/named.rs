@@ -15,47 +15,110 @@
 //! so that you can associate their `id` with the proper submenu.
 //!
 //! [`TreeMenu`]: crate::resolve::TreeMenu
-use std::mem;
-
+use bevy::ecs::entity::EntityHashMap;
 use bevy::ecs::prelude::*;
 use bevy::log::{debug, warn};
+use bevy::platform::collections::HashMap;
 use bevy::time::Time;
 
 use crate::{menu::MenuBuilder, resolve::Focusable};
 
+/// Index of [`Focusable`]s by [`Name`], used to resolve
+/// [`MenuBuilder::NamedParent`] without iterating every named focusable.
+///
+/// Several focusables may share the same name,
+/// the first one added is used as the menu parent.
+#[derive(Resource, Default)]
+pub(crate) struct NamedFocusables {
+    by_name: HashMap<Name, Vec<Entity>>,
+    names: EntityHashMap<Name>,
+}
+impl NamedFocusables {
+    fn get(&self, name: &Name) -> Option<Entity> {
+        self.by_name.get(name)?.first().copied()
+    }
+    fn insert(&mut self, entity: Entity, name: &Name) {
+        if self.names.get(&entity) == Some(name) {
+            return;
+        }
+        self.remove(entity);
+        self.names.insert(entity, name.clone());
+        self.by_name.entry(name.clone()).or_default().push(entity);
+    }
+    fn remove(&mut self, entity: Entity) {
+        let Some(name) = self.names.remove(&entity) else {
+            return;
+        };
+        let Some(entities) = self.by_name.get_mut(&name) else {
+            return;
+        };
+        entities.retain(|e| *e != entity);
+        if entities.is_empty() {
+            self.by_name.remove(&name);
+        }
+    }
+}
+
+/// Keep [`NamedFocusables`] up to date with added, renamed
+/// and removed named [`Focusable`]s.
+#[allow(clippy::type_complexity)]
+pub(crate) fn index_named_focusables(
+    mut index: ResMut<NamedFocusables>,
+    changed: Query<(Entity, &Name), (With<Focusable>, Or<(Changed<Name>, Added<Focusable>)>)>,
+    mut removed_focusables: RemovedComponents<Focusable>,
+    mut removed_names: RemovedComponents<Name>,
+) {
+    let removed = removed_focusables.read().chain(removed_names.read());
+    let removed: Vec<_> = removed.filter(|e| index.names.contains_key(e)).collect();
+    if removed.is_empty() && changed.is_empty() {
+        return;
+    }
+    for entity in removed {
+        index.remove(entity);
+    }
+    for (entity, name) in &changed {
+        index.insert(entity, name);
+    }
+}
+
+/// Replace [`MenuBuilder::NamedParent`] with [`MenuBuilder::EntityParent`]
+/// when a [`Focusable`] with the given [`Name`] exists.
+///
+/// A `MenuBuilder` is only checked when it or [`NamedFocusables`] changed,
+/// and once a second to warn about unresolved names.
 pub(crate) fn resolve_named_menus(
     mut unresolved: Query<(Entity, &mut MenuBuilder)>,
-    named: Query<(Entity, &Name), With<Focusable>>,
+    index: Res<NamedFocusables>,
     time: Option<Res<Time>>,
 ) {
-    use MenuBuilder::{EntityParent, NamedParent, Root};
+    use MenuBuilder::NamedParent;
     let each_second = || {
         let Some(time) = &time else { return true };
         time.elapsed_secs_f64().fract() < time.delta_secs_f64()
     };
+    let should_warn = each_second();
+    let run_all = index.is_changed() || should_warn;
     for (entity, mut builder) in &mut unresolved {
-        let parent_name = match &mut *builder {
-            NamedParent(name) => mem::take(name),
+        if !run_all && !builder.is_changed() {
+            continue;
+        }
+        let NamedParent(parent_name) = &*builder else {
             // Already resolved / do not need to resolve name
-            EntityParent(_) | Root => continue,
+            continue;
         };
-        let with_parent_name = |(e, n)| (&parent_name == n).then_some(e);
-        match named.iter().find_map(with_parent_name) {
+        match index.get(parent_name) {
             Some(focus_parent) => {
                 debug!("Found parent focusable with name '{parent_name}' for menu {entity:?}");
                 *builder = MenuBuilder::EntityParent(focus_parent);
             }
-            None if each_second() => {
+            None if should_warn => {
                 warn!(
                     "Tried to spawn menu {entity:?} with parent focusable \
                     '{parent_name}', but no Focusable has a Name component \
                     with that value."
                 );
-                *builder = NamedParent(parent_name);
-            }
-            None => {
-                *builder = NamedParent(parent_name);
             }
+            None => {}
         }
     }
 }
@@ -27,6 +27,7 @@
 //!
 //! We define some `SystemParam`:
 //! * [`ChildQueries`]: queries used to find the focusable children of a given entity.
+//! * [`HiddenQueries`]: queries used to tell whether a focusable is hidden.
 //! * [`NavQueries`]: All **immutable** queries used by the resolution algorithm.
 //! * [`MutQueries`]: Queries with mutable access to [`Focusable`] and [`TreeMenu`]
 //!   for updating them in [`listen_nav_requests`].
@@ -37,13 +38,26 @@
 //! navigation resolution and mutably for updating them with the new navigation state.
 use std::num::NonZeroUsize;
 
+use bevy::ecs::entity::{EntityHashMap, EntityHashSet};
 use bevy::ecs::hierarchy::{ChildOf, Children};
 #[cfg(feature = "bevy_reflect")]
 use bevy::ecs::reflect::{ReflectComponent, ReflectResource};
-use bevy::log::{debug, warn};
-use bevy::prelude::{Changed, FromWorld};
+use bevy::ecs::{component::HookContext, world::DeferredWorld, world::World};
+use bevy::log::{debug, error, warn};
+use bevy::platform::collections::HashMap;
+use bevy::prelude::{
+    Added, Changed, DetectChanges, FromWorld, In, InheritedVisibility, Local, Or, Ref,
+    RemovedComponents,
+};
 #[cfg(feature = "bevy_reflect")]
 use bevy::reflect::Reflect;
+use bevy::{
+    ecs::change_detection::DetectChangesMut,
+    math::FloatOrd,
+    math::Vec3Swizzles,
+    prelude::{GlobalTransform, Res},
+    ui::{Display, Node},
+};
 use bevy::{
     ecs::{
         event::{EventReader, EventWriter},
@@ -52,18 +66,17 @@ use bevy::{
     },
     math::Vec2,
 };
-use bevy::{
-    math::FloatOrd,
-    math::Vec3Swizzles,
-    prelude::{GlobalTransform, Res},
-};
 
 use non_empty_vec::NonEmpty;
 
 use crate::{
     commands::set_focus_state,
-    events::{self, NavEvent, NavRequest},
+    events::{
+        self, Activated, BecameActive, DeadEnd, DeadEndReason, FocusGained, FocusLost, Locked,
+        MenuEntered, MenuExited, NavDiagnostic, NavEvent, NavRequest, RootCancelled,
+    },
     menu::{MenuBuilder, MenuSetting},
+    spatial::FocusableGrid,
 };
 
 /// System parameter used to resolve movement and cycling focus updates.
@@ -89,6 +102,41 @@ pub trait MenuNavigationStrategy {
         cycles: bool,
         siblings: &'a [Entity],
     ) -> Option<&'a Entity>;
+
+    /// Like [`Self::resolve_2d`], but report why no entity can be reached.
+    ///
+    /// This is used to fill [`DeadEnd::reason`]. The default implementation
+    /// reports [`DeadEndReason::Edge`] when the menu doesn't cycle,
+    /// and [`DeadEndReason::NoCandidate`] otherwise.
+    fn try_resolve_2d<'a>(
+        &self,
+        focused: Entity,
+        direction: events::Direction,
+        cycles: bool,
+        siblings: &'a [Entity],
+    ) -> Result<&'a Entity, DeadEndReason> {
+        let reason = if cycles {
+            DeadEndReason::NoCandidate
+        } else {
+            DeadEndReason::Edge
+        };
+        self.resolve_2d(focused, direction, cycles, siblings)
+            .ok_or(reason)
+    }
+
+    /// Whether [`Self::resolve_2d`] picks the closest sibling in `direction`
+    /// based on the x and y coordinates of their [`GlobalTransform`].
+    ///
+    /// When `true`, moving between focusables that are not in a menu
+    /// passes as `siblings` the closest focusable found with the
+    /// [`FocusableGrid`] instead of all focusables.
+    /// Focusables spawned this frame are not in the grid yet.
+    /// `false` by default.
+    ///
+    /// [`FocusableGrid`]: crate::spatial::FocusableGrid
+    fn picks_closest(&self) -> bool {
+        false
+    }
 }
 
 /// A rectangle to specify the [`ScreenBoundaries`],
@@ -118,12 +166,215 @@ pub struct ScreenBoundaries {
     pub scale: f32,
 }
 
+/// Global settings of the navigation system.
+///
+/// The navigation plugin inserts the default settings,
+/// overwrite the resource to change them.
+#[derive(Default, Debug, Clone, Copy, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct NavSettings {
+    /// Whether to treat hidden [`Focusable`]s as [`FocusState::Blocked`].
+    ///
+    /// A `Focusable` is hidden when its [`InheritedVisibility`] is not visible,
+    /// or when it or any of its ancestors has a [`Node`] with
+    /// [`Display::None`].
+    ///
+    /// Hidden focusables are skipped when picking the first focused entity,
+    /// when moving between siblings and when picking with the mouse.
+    /// Their [`Focusable::state`] is left untouched.
+    ///
+    /// This is `false` by default, since the check requires walking up
+    /// the hierarchy of each candidate focusable.
+    pub skip_hidden: bool,
+
+    /// Panic on [`NavDiagnostic`]s instead of logging them.
+    ///
+    /// By default, problems with how the navigation tree is built,
+    /// such as menu cycles, are logged as errors
+    /// and sent as [`NavDiagnostic`] events, and the offending menu is skipped.
+    /// Set this to `true` to catch those problems early during development.
+    pub strict: bool,
+}
+
+/// Report [`NavDiagnostic`]s according to [`NavSettings::strict`].
+///
+/// Use it to report problems with the navigation tree
+/// from your own input systems.
+#[derive(SystemParam)]
+pub struct NavDiagnostics<'w> {
+    settings: Option<Res<'w, NavSettings>>,
+    events: EventWriter<'w, NavDiagnostic>,
+}
+impl NavDiagnostics<'_> {
+    /// Log `diagnostic` and send it as an event,
+    /// or panic if [`NavSettings::strict`] is set.
+    pub fn report(&mut self, diagnostic: NavDiagnostic) {
+        if self.settings.as_ref().is_some_and(|s| s.strict) {
+            panic!("{diagnostic}");
+        }
+        error!("{diagnostic}");
+        self.events.write(diagnostic);
+    }
+}
+
+/// The currently focused [`Focusable`] and the path to it from the root menu.
+///
+/// This is updated alongside the [`Focused`] marker,
+/// within the [`NavRequestSystem`](crate::NavRequestSystem).
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_alt_ui_navigation_lite::prelude::*;
+/// fn breadcrumbs(path: Res<FocusPath>, names: Query<&Name>) {
+///     if path.is_changed() {
+///         let names = path.path().iter().rev().filter_map(|e| names.get(*e).ok());
+///         info!("{:?}", names.collect::<Vec<_>>());
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct FocusPath {
+    path: Vec<Entity>,
+    menus: Vec<Entity>,
+}
+impl FocusPath {
+    /// The focused entity, if any.
+    pub fn focused(&self) -> Option<Entity> {
+        self.path.first().copied()
+    }
+    /// The [`FocusState::Active`] focusables, from the innermost one
+    /// to the one in the root menu.
+    pub fn active(&self) -> &[Entity] {
+        self.path.get(1..).unwrap_or_default()
+    }
+    /// The focused entity followed by the [`active`](Self::active) ones.
+    pub fn path(&self) -> &[Entity] {
+        &self.path
+    }
+    /// The menus containing each element of the [`path`](Self::path),
+    /// from the innermost one to the root menu.
+    ///
+    /// Empty when the focused entity is not in a menu.
+    pub fn menus(&self) -> &[Entity] {
+        &self.menus
+    }
+}
+
+/// Queries used to tell whether a [`Focusable`] is hidden,
+/// see [`NavSettings::skip_hidden`].
+#[derive(SystemParam)]
+pub struct HiddenQueries<'w, 's> {
+    settings: Option<Res<'w, NavSettings>>,
+    visibility: Query<'w, 's, &'static InheritedVisibility>,
+    nodes: Query<'w, 's, &'static Node>,
+    parents: Query<'w, 's, &'static ChildOf>,
+}
+impl HiddenQueries<'_, '_> {
+    /// Whether `entity` should be skipped because it is not visible.
+    ///
+    /// Always `false` if [`NavSettings::skip_hidden`] is not set.
+    pub(crate) fn is_hidden(&self, entity: Entity) -> bool {
+        if !self.settings.as_ref().is_some_and(|s| s.skip_hidden) {
+            return false;
+        }
+        if self.visibility.get(entity).is_ok_and(|v| !v.get()) {
+            return true;
+        }
+        let undisplayed = |e| self.nodes.get(e).is_ok_and(|n| n.display == Display::None);
+        undisplayed(entity) || self.parents.iter_ancestors(entity).any(undisplayed)
+    }
+}
+
 #[derive(SystemParam)]
 pub(crate) struct ChildQueries<'w, 's> {
-    children: Query<'w, 's, &'static Children>,
+    hierarchy: MenuHierarchy<'w, 's>,
     is_focusable: Query<'w, 's, &'static Focusable>,
+    cache: Res<'w, MenuFocusables>,
+    pub(crate) hidden: HiddenQueries<'w, 's>,
+}
+
+/// Queries to walk the hierarchy of a menu.
+#[derive(SystemParam)]
+pub(crate) struct MenuHierarchy<'w, 's> {
+    children: Query<'w, 's, &'static Children>,
+    is_focusable: Query<'w, 's, (), With<Focusable>>,
     is_menu: Query<'w, 's, (), With<MenuSetting>>,
 }
+impl MenuHierarchy<'_, '_> {
+    /// All [`Focusable`]s within `menu`, including blocked and hidden ones.
+    ///
+    /// Focusables in nested menus are not included.
+    fn all_focusables(&self, menu: Entity) -> Vec<Entity> {
+        let Ok(direct_children) = self.children.get(menu) else {
+            return Vec::new();
+        };
+        let focusables = direct_children
+            .iter()
+            .filter(|e| self.is_focusable.contains(**e))
+            .copied();
+        let transitive_focusables = direct_children
+            .iter()
+            .filter(|e| !self.is_focusable.contains(**e))
+            .filter(|e| !self.is_menu.contains(**e))
+            .flat_map(|e| self.all_focusables(*e));
+        focusables.chain(transitive_focusables).collect()
+    }
+}
+
+/// The [`Focusable`]s within each menu, in the order returned by
+/// [`ChildQueries::focusables_of`].
+///
+/// Blocked and hidden focusables are included, since their state
+/// changes more often than the hierarchy,
+/// they are filtered out when reading the cache.
+///
+/// This is updated by [`update_menu_focusables`].
+#[derive(Resource, Default)]
+pub(crate) struct MenuFocusables {
+    menus: EntityHashMap<Vec<Entity>>,
+}
+
+/// Update [`MenuFocusables`] for menus whose hierarchy changed,
+/// or where focusables were added or removed.
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_menu_focusables(
+    mut cache: ResMut<MenuFocusables>,
+    changed: Query<
+        Entity,
+        Or<(
+            Changed<Children>,
+            Changed<ChildOf>,
+            Added<Focusable>,
+            Added<MenuSetting>,
+        )>,
+    >,
+    mut removed_focusables: RemovedComponents<Focusable>,
+    mut removed_menus: RemovedComponents<MenuSetting>,
+    mut removed_children: RemovedComponents<Children>,
+    parents: Query<&ChildOf>,
+    hierarchy: MenuHierarchy,
+) {
+    let is_menu = |e: &Entity| hierarchy.is_menu.contains(*e);
+    let removed_menus: Vec<_> = removed_menus.read().collect();
+    for menu in &removed_menus {
+        cache.menus.remove(menu);
+    }
+    let removed = removed_focusables.read().chain(removed_children.read());
+    let removed = removed.chain(removed_menus);
+    let mut dirty = EntityHashSet::default();
+    for entity in changed.iter().chain(removed) {
+        dirty.extend(Some(entity).filter(is_menu));
+        dirty.extend(parents.iter_ancestors(entity).find(is_menu));
+    }
+    for menu in dirty {
+        if is_menu(&menu) {
+            cache.menus.insert(menu, hierarchy.all_focusables(menu));
+        }
+    }
+}
 
 /// System parameter for the default cursor navigation system.
 ///
@@ -146,6 +397,8 @@ pub(crate) struct NavQueries<'w, 's> {
     parents: Query<'w, 's, &'static ChildOf>,
     focusables: Query<'w, 's, (Entity, &'static Focusable), Without<TreeMenu>>,
     menus: Query<'w, 's, (Entity, &'static TreeMenu, &'static MenuSetting), Without<Focusable>>,
+    child_menus: Query<'w, 's, &'static ChildMenus>,
+    grid: Option<Res<'w, FocusableGrid>>,
 }
 impl NavQueries<'_, '_> {
     fn active_menu(
@@ -161,9 +414,9 @@ impl NavQueries<'_, '_> {
                     return None;
                 }
                 let (new_menu_entity, child_menu, _) = child_menu(active_child, self)?;
+                active_child = child_menu.active_child?;
                 repeated = true;
                 entity = new_menu_entity;
-                active_child = child_menu.active_child;
                 Some(())
             };
             match go_down_one_menu() {
@@ -174,6 +427,19 @@ impl NavQueries<'_, '_> {
         }
     }
 
+    /// The focusable to focus when entering `menu`.
+    ///
+    /// This is its `active_child`, or its first focusable
+    /// when the `active_child` became hidden.
+    fn entered_child(&self, menu: Entity, tree: &TreeMenu) -> Option<Entity> {
+        match tree.active_child? {
+            child if self.children.hidden.is_hidden(child) => {
+                self.children.focusables_of(menu).next()
+            }
+            child => Some(child),
+        }
+    }
+
     /// The [`TreeMenu`] containing `focusable`, if any.
     pub(crate) fn parent_menu(&self, focusable: Entity) -> Option<(Entity, TreeMenu, MenuSetting)> {
         let parent = self.parents.get(focusable).ok()?.parent();
@@ -183,28 +449,60 @@ impl NavQueries<'_, '_> {
         }
     }
 
+    /// The [`FocusPath`] of `focused`.
+    fn focus_path(&self, focused: Entity) -> FocusPath {
+        let path = self.root_path(focused).to_vec();
+        let menus = path.iter().filter_map(|e| self.parent_menu(*e));
+        let menus = menus.map(|(menu, ..)| menu).collect();
+        FocusPath { path, menus }
+    }
+
+    /// The menus exited and entered when focus goes from `from` to `to`.
+    ///
+    /// Exited menus are innermost first, entered menus are outermost first.
+    fn menu_changes(&self, from: &[Entity], to: &[Entity]) -> (Vec<Entity>, Vec<Entity>) {
+        let menus_of = |path: &[Entity]| -> Vec<Entity> {
+            let menus = path.iter().filter_map(|e| self.parent_menu(*e));
+            menus.map(|(menu, ..)| menu).collect()
+        };
+        let (from, to) = (menus_of(from), menus_of(to));
+        let exited = from.iter().filter(|m| !to.contains(m)).copied().collect();
+        let entered = to
+            .iter()
+            .rev()
+            .filter(|m| !from.contains(m))
+            .copied()
+            .collect();
+        (exited, entered)
+    }
+
     // TODO: worst case this iterates 3 times through list of focusables and once menus.
     // Could be improved to a single pass.
     fn pick_first_focused(&self) -> Option<Entity> {
         use FocusState::{Blocked, Focused, Inert};
-        let iter_focused = || self.focusables.iter().filter(|f| f.1.state() != Blocked);
+        let hidden = |entity| self.children.hidden.is_hidden(entity);
+        let iter_focused = || {
+            self.focusables
+                .iter()
+                .filter(move |f| f.1.state() != Blocked && !hidden(f.0))
+        };
         let root_menu = || {
             self.menus
                 .iter()
                 .find(|(_, menu, _)| menu.focus_parent.is_none())
         };
-        let any_in_menu = |entity, active_child| {
-            match self.focusables.get(active_child) {
-                Ok((entity, _)) => Some(entity),
+        let any_in_menu = |entity, active_child: Option<Entity>| {
+            match active_child.and_then(|child| self.focusables.get(child).ok()) {
+                Some((entity, _)) if !hidden(entity) => Some(entity),
                 // TODO: non-Inert non-active_child
-                Err(_) => self.children.focusables_of(entity).first().copied(),
+                _ => self.children.focusables_of(entity).next(),
             }
         };
         let any_in_active = || {
             let (root_menu_entity, menu, _) = root_menu()?;
             let (active_menu_entity, active) =
-                self.active_menu(root_menu_entity, menu.active_child)?;
-            any_in_menu(active_menu_entity, active)
+                self.active_menu(root_menu_entity, menu.active_child?)?;
+            any_in_menu(active_menu_entity, Some(active))
         };
         let any_in_root = || {
             let (root_menu_entity, menu, _) = root_menu()?;
@@ -213,33 +511,131 @@ impl NavQueries<'_, '_> {
         let any_prioritized =
             || iter_focused().find_map(|(e, focus)| (focus.state != Inert).then(|| e));
         let fallback = || iter_focused().next().map(|(fo, _)| fo);
-        let focused = iter_focused().find_map(|(fo, focus)| (focus.state == Focused).then(|| fo));
+        let focused = || {
+            let mut focusables = self.focusables.iter();
+            focusables.find_map(|(fo, focus)| (focus.state == Focused).then(|| fo))
+        };
 
-        focused
+        focused()
             .or_else(any_in_active)
             .or_else(any_prioritized)
             .or_else(any_in_root)
             .or_else(fallback)
     }
 
-    fn root_path(&self, mut from: Entity) -> NonEmpty<Entity> {
+    fn root_path(&self, from: Entity) -> NonEmpty<Entity> {
+        self.checked_root_path(from).0
+    }
+
+    /// The [`root_path`](Self::root_path) of `from`, and the menu
+    /// where it loops back on itself, if any.
+    ///
+    /// Systems report the cycle as a [`NavDiagnostic`].
+    fn checked_root_path(&self, mut from: Entity) -> (NonEmpty<Entity>, Option<Entity>) {
         let mut ret = NonEmpty::new(from);
         loop {
             from = match self.parent_menu(from) {
                 // purely personal preference over deeply nested pattern match
                 Some((_, menu, _)) if menu.focus_parent.is_some() => menu.focus_parent.unwrap(),
-                _ => return ret,
+                _ => return (ret, None),
             };
-            assert!(
-                !ret.contains(&from),
-                "Navigation graph cycle detected! This panic has prevented a stack \
-                overflow, please check usages of `MenuBuilder::Entity/NamedParent`"
-            );
+            if ret.contains(&from) {
+                let menu = self.parent_menu(from).map_or(from, |(menu, ..)| menu);
+                return (ret, Some(menu));
+            }
             ret.push(from);
         }
     }
 }
 
+/// Read-only access to the navigation tree.
+///
+/// Use it in custom [`MenuNavigationStrategy`]s or widgets
+/// to inspect how [`Focusable`]s and menus relate to each other,
+/// following the same rules as the navigation plugin.
+///
+/// A menu, in this API, is an entity with a [`MenuSetting`] component,
+/// once it is initialized by the navigation plugin.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_alt_ui_navigation_lite::custom::NavTree;
+///
+/// fn breadcrumbs(tree: NavTree, names: Query<&Name>) {
+///     let Some(path) = tree.focused_path() else {
+///         return;
+///     };
+///     let names: Vec<_> = path.iter().rev().filter_map(|e| names.get(*e).ok()).collect();
+///     info!("{names:?}");
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct NavTree<'w, 's> {
+    queries: NavQueries<'w, 's>,
+}
+impl NavTree<'_, '_> {
+    /// The menu containing `focusable`, if any.
+    pub fn parent_menu(&self, focusable: Entity) -> Option<Entity> {
+        self.queries.parent_menu(focusable).map(|(menu, ..)| menu)
+    }
+    /// The menu reachable from `focusable`, if any.
+    ///
+    /// This is the menu focus goes into when `focusable` is activated.
+    pub fn child_menu(&self, focusable: Entity) -> Option<Entity> {
+        child_menu(focusable, &self.queries).map(|(menu, ..)| menu)
+    }
+    /// The [`Focusable`] `menu` is reachable from, if any.
+    ///
+    /// This is `None` for root menus and for entities that are not menus.
+    pub fn focus_parent(&self, menu: Entity) -> Option<Entity> {
+        let (_, tree, _) = self.queries.menus.get(menu).ok()?;
+        tree.focus_parent
+    }
+    /// The currently prioritized or active focusable in `menu`, if any.
+    ///
+    /// This is `None` for menus without any focusables.
+    pub fn active_child(&self, menu: Entity) -> Option<Entity> {
+        let (_, tree, _) = self.queries.menus.get(menu).ok()?;
+        tree.active_child
+    }
+    /// The [`MenuSetting`] of `menu`, if it is a menu.
+    pub fn menu_setting(&self, menu: Entity) -> Option<MenuSetting> {
+        let (_, _, setting) = self.queries.menus.get(menu).ok()?;
+        Some(*setting)
+    }
+    /// The non-blocked [`Focusable`]s directly within `menu`.
+    ///
+    /// Focusables in nested menus are not included.
+    pub fn siblings(&self, menu: Entity) -> Vec<Entity> {
+        self.queries.children.focusables_of(menu).collect()
+    }
+    /// The path from `focusable` to the root menu.
+    ///
+    /// The first element is `focusable`, following elements are
+    /// the focusables each menu is reachable from.
+    pub fn root_path(&self, focusable: Entity) -> NonEmpty<Entity> {
+        self.queries.root_path(focusable)
+    }
+    /// The currently focused [`Focusable`], if any.
+    pub fn focused(&self) -> Option<Entity> {
+        let is_focused = |(_, focus): &(_, &Focusable)| focus.state() == FocusState::Focused;
+        self.queries
+            .focusables
+            .iter()
+            .find(is_focused)
+            .map(|(e, _)| e)
+    }
+    /// The [`root_path`](Self::root_path) of the [`focused`](Self::focused) entity.
+    ///
+    /// The first element is the focused entity, following elements are
+    /// [`FocusState::Active`] focusables.
+    pub fn focused_path(&self) -> Option<NonEmpty<Entity>> {
+        self.focused().map(|focused| self.root_path(focused))
+    }
+}
+
 /// Queries [`Focusable`] and [`TreeMenu`] in a mutable way.
 #[derive(SystemParam)]
 pub(crate) struct MutQueries<'w, 's> {
@@ -265,9 +661,10 @@ impl MutQueries<'_, '_> {
                 return;
             }
         };
-        let entity = nav_menu.active_child;
-        nav_menu.active_child = child;
-        self.set_entity_focus(entity, FocusState::Inert);
+        let previous = nav_menu.active_child.replace(child);
+        if let Some(entity) = previous.filter(|entity| *entity != child) {
+            self.set_entity_focus(entity, FocusState::Inert);
+        }
     }
 
     fn set_entity_focus(&mut self, entity: Entity, state: FocusState) {
@@ -277,6 +674,70 @@ impl MutQueries<'_, '_> {
         }
     }
 
+    /// Trigger the entity-targeted observer events corresponding to `event`.
+    fn trigger_events(&mut self, event: &NavEvent) {
+        match event {
+            NavEvent::FocusChanged { to, from, .. } => self.trigger_focus_events(from, to),
+            NavEvent::NoChanges {
+                from,
+                request: NavRequest::Action,
+            } => {
+                self.commands.trigger_targets(Activated, *from.first());
+            }
+            NavEvent::Locked(LockReason::Focusable(locking)) => {
+                self.commands.trigger_targets(Locked, *locking);
+            }
+            _ => {}
+        }
+    }
+
+    /// Trigger [`FocusLost`], [`FocusGained`] and [`BecameActive`]
+    /// for a focus change from `from` to `to`, see [`Self::update_focus`].
+    fn trigger_focus_events(&mut self, from: &[Entity], to: &NonEmpty<Entity>) {
+        let (&focused, activated) = to.split_first();
+        if from.first() != Some(&focused) {
+            if let Some(&lost) = from.first() {
+                self.commands.trigger_targets(FocusLost, lost);
+            }
+            self.commands.trigger_targets(FocusGained, focused);
+        }
+        // `from[0]` was focused, not active, when entering its child menu.
+        let was_active = from.get(1..).unwrap_or_default();
+        let activated: Vec<_> = activated
+            .iter()
+            .filter(|entity| !was_active.contains(entity))
+            .copied()
+            .collect();
+        if !activated.is_empty() {
+            self.commands.trigger_targets(BecameActive, activated);
+        }
+    }
+
+    /// Trigger [`DeadEnd`] on `focused`.
+    fn trigger_dead_end(&mut self, focused: Entity, dead_end: DeadEnd) {
+        self.commands.trigger_targets(dead_end, focused);
+    }
+
+    /// Trigger the events of `outcome` on `focused`.
+    fn trigger_outcome(&mut self, focused: Entity, outcome: Outcome) {
+        if let Some(dead_end) = outcome.dead_end {
+            self.trigger_dead_end(focused, dead_end);
+        }
+        if outcome.root_cancelled {
+            self.commands.trigger_targets(RootCancelled, focused);
+        }
+    }
+
+    /// Trigger [`MenuExited`] and [`MenuEntered`], see [`NavQueries::menu_changes`].
+    fn trigger_menu_events(&mut self, (exited, entered): (Vec<Entity>, Vec<Entity>)) {
+        if !exited.is_empty() {
+            self.commands.trigger_targets(MenuExited, exited);
+        }
+        if !entered.is_empty() {
+            self.commands.trigger_targets(MenuEntered, entered);
+        }
+    }
+
     /// Change focus state of relevant entities.
     fn update_focus(&mut self, from: &[Entity], to: &NonEmpty<Entity>) -> Entity {
         use FocusState as Fs;
@@ -405,7 +866,9 @@ pub(crate) struct TreeMenu {
     /// when receiving [`NavRequest::Action`].
     pub(crate) focus_parent: Option<Entity>,
     /// The currently prioritized or active focusable in this menu.
-    pub(crate) active_child: Entity,
+    ///
+    /// `None` when the menu doesn't contain any focusable yet.
+    pub(crate) active_child: Option<Entity>,
 }
 impl FromWorld for TreeMenu {
     // PLEASE DO NOT USE THIS.
@@ -414,11 +877,32 @@ impl FromWorld for TreeMenu {
     fn from_world(_: &mut bevy::prelude::World) -> Self {
         TreeMenu {
             focus_parent: None,
-            active_child: Entity::PLACEHOLDER,
+            active_child: None,
         }
     }
 }
 
+/// Relationship from a [`TreeMenu`] to its [`focus_parent`](TreeMenu::focus_parent).
+///
+/// This mirrors `focus_parent`, so that the menu reachable from a focusable
+/// is found through [`ChildMenus`] rather than by iterating all menus.
+#[derive(Debug, Component, Clone, Copy, PartialEq, Eq)]
+#[relationship(relationship_target = ChildMenus)]
+pub(crate) struct MenuParent(pub(crate) Entity);
+
+/// The menus reachable from this [`Focusable`], see [`MenuParent`].
+#[derive(Debug, Component, Default)]
+#[relationship_target(relationship = MenuParent)]
+pub(crate) struct ChildMenus(Vec<Entity>);
+
+/// Keep the [`MenuParent`] of `menu` in sync with its `focus_parent`.
+fn set_menu_parent(commands: &mut Commands, menu: Entity, focus_parent: Option<Entity>) {
+    match focus_parent {
+        Some(parent) => commands.entity(menu).insert(MenuParent(parent)),
+        None => commands.entity(menu).remove::<MenuParent>(),
+    };
+}
+
 /// The actions triggered by a [`Focusable`].
 #[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
 #[non_exhaustive]
@@ -461,6 +945,7 @@ pub enum FocusAction {
 /// **Note**: You should avoid updating manually the state of [`Focusable`]s.
 /// You should instead use [`NavRequest`] to manipulate and change focus.
 #[derive(Component, Clone, Debug)]
+#[component(on_add = on_add_focusable, on_remove = on_remove_focusable)]
 #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
 pub struct Focusable {
     pub(crate) state: FocusState,
@@ -607,15 +1092,18 @@ impl Focusable {
 ///
 /// # Notes
 ///
-/// The `Focused` marker component is only updated
-/// at the end of the `CoreStage::Update` stage.
-/// This means it might lead to a single frame of latency
-/// compared to using [`Focusable::state()`].
+/// The `Focused` marker component is updated within the
+/// [`NavRequestSystem`](crate::NavRequestSystem) set.
+/// Systems running after it see the same focused entity
+/// in `Focused`, [`Focusable::state()`] and the emitted [`NavEvent`]s.
 #[derive(Component)]
 #[non_exhaustive]
 pub struct Focused;
 
 impl MenuNavigationStrategy for UiProjectionQuery<'_, '_> {
+    fn picks_closest(&self) -> bool {
+        true
+    }
     fn resolve_2d<'a>(
         &self,
         focused: Entity,
@@ -683,6 +1171,8 @@ fn resolve_scope(
 }
 
 /// Find the event created by `request` where the focused element is `focused`.
+///
+/// Results that are not part of the [`NavEvent`] are set in `outcome`.
 fn resolve<STGY: MenuNavigationStrategy>(
     focused: Entity,
     request: NavRequest,
@@ -691,21 +1181,27 @@ fn resolve<STGY: MenuNavigationStrategy>(
     lock: &mut ResMut<NavLock>,
     from: Vec<Entity>,
     strategy: &STGY,
+    outcome: &mut Outcome,
 ) -> NavEvent {
     use FocusState::Blocked;
     use NavRequest::*;
 
-    assert!(
-        queries.focusables.get(focused).is_ok(),
-        "The resolution algorithm MUST go from a focusable element"
-    );
-    assert!(
-        !from.contains(&focused),
-        "Navigation graph cycle detected! This panic has prevented a stack overflow, \
-        please check usages of `MenuSetting::reachable_from`"
-    );
-
+    if !queries.focusables.contains(focused) {
+        outcome.diagnostic = Some(NavDiagnostic::MissingFocusable(focused));
+        return NavEvent::NoChanges {
+            from: (from, focused).into(),
+            request,
+        };
+    }
+    let cycles = from.contains(&focused);
     let mut from = (from, focused).into();
+    if cycles {
+        let menu = queries
+            .parent_menu(focused)
+            .map_or(focused, |(menu, ..)| menu);
+        outcome.diagnostic = Some(NavDiagnostic::Cycle(menu));
+        return NavEvent::NoChanges { from, request };
+    }
 
     // Early exit with a `NoChanges` event.
     macro_rules! or_none {
@@ -726,31 +1222,62 @@ fn resolve<STGY: MenuNavigationStrategy>(
             NavEvent::Locked(reason)
         }
         Move(direction) => {
+            let mut dead_end = |menu, reason| {
+                outcome.dead_end = Some(DeadEnd {
+                    direction,
+                    menu,
+                    reason,
+                });
+            };
             let (parent, cycles) = match queries.parent_menu(focused) {
-                Some(val) if !val.2.is_2d() => return NavEvent::NoChanges { from, request },
+                Some(val) if !val.2.is_2d() => {
+                    dead_end(Some(val.0), DeadEndReason::ScopeMenu);
+                    return NavEvent::NoChanges { from, request };
+                }
                 Some(val) => (Some(val.0), !val.2.bound()),
                 None => (None, true),
             };
-            let unblocked = |(e, focus): (_, &Focusable)| (focus.state != Blocked).then(|| e);
+            let hidden = |e| queries.children.hidden.is_hidden(e);
+            let unblocked =
+                |(e, focus): (_, &Focusable)| (focus.state != Blocked && !hidden(e)).then(|| e);
+            let closest = || {
+                let grid = queries.grid.as_ref().filter(|_| strategy.picks_closest())?;
+                let from = grid.position(focused)?;
+                let candidate = |e| queries.focusables.get(e).ok().and_then(unblocked);
+                grid.closest_in(from, direction, |e| e != focused && candidate(e).is_some())
+            };
             let siblings = match parent {
-                Some(parent) => queries.children.focusables_of(parent),
-                None => queries.focusables.iter().filter_map(unblocked).collect(),
+                Some(parent) => queries.children.focusables_of(parent).collect(),
+                // Without a closest focusable, all are needed to cycle.
+                None => match closest() {
+                    Some(closest) => vec![focused, closest],
+                    None => queries.focusables.iter().filter_map(unblocked).collect(),
+                },
             };
-            let to = strategy.resolve_2d(focused, direction, cycles, &siblings);
-            NavEvent::focus_changed(*or_none!(to), from)
+            match strategy.try_resolve_2d(focused, direction, cycles, &siblings) {
+                Ok(to) => NavEvent::focus_changed(*to, from, request),
+                Err(reason) => {
+                    dead_end(parent, reason);
+                    NavEvent::NoChanges { from, request }
+                }
+            }
         }
         Cancel => {
-            let to = or_none!(queries.parent_menu(focused));
-            let to = or_none!(to.1.focus_parent);
+            let parent = queries.parent_menu(focused);
+            let Some(to) = parent.and_then(|(_, menu, _)| menu.focus_parent) else {
+                outcome.root_cancelled = true;
+                return NavEvent::NoChanges { from, request };
+            };
             from.push(to);
-            NavEvent::focus_changed(to, from)
+            NavEvent::focus_changed(to, from, request)
         }
         Action => {
             match queries.focusables.get(focused).map(|e| e.1.action) {
                 Ok(FocusAction::Cancel) => {
                     let mut from = from.to_vec();
                     from.truncate(from.len() - 1);
-                    return resolve(focused, NavRequest::Cancel, queries, lock, from, strategy);
+                    let request = NavRequest::Cancel;
+                    return resolve(focused, request, queries, lock, from, strategy, outcome);
                 }
                 Ok(FocusAction::Lock) => {
                     let reason = LockReason::Focusable(focused);
@@ -760,38 +1287,49 @@ fn resolve<STGY: MenuNavigationStrategy>(
                 Err(_) | Ok(FocusAction::Normal) => {}
             }
             let child_menu = child_menu(focused, queries);
-            let (_, menu, _) = or_none!(child_menu);
-            let to = (menu.active_child, from.clone().into()).into();
-            NavEvent::FocusChanged { to, from }
+            let (menu_entity, menu, _) = or_none!(child_menu);
+            // Empty menus can't be entered until focusables are spawned in them.
+            let entered = queries.entered_child(menu_entity, menu);
+            let to = (or_none!(entered), from.clone().into()).into();
+            NavEvent::FocusChanged { to, from, request }
         }
         // "Tab move" nested movement
         ScopeMove(scope_dir) => {
             let (parent, menu, setting) = or_none!(queries.parent_menu(focused));
-            let siblings = queries.children.focusables_of(parent);
+            let siblings: Vec<_> = queries.children.focusables_of(parent).collect();
             if !setting.is_scope() {
                 let focused = or_none!(menu.focus_parent);
-                resolve(focused, request, queries, lock, from.into(), strategy)
+                resolve(
+                    focused,
+                    request,
+                    queries,
+                    lock,
+                    from.into(),
+                    strategy,
+                    outcome,
+                )
             } else {
                 let cycles = !setting.bound();
                 let to = or_none!(resolve_scope(focused, scope_dir, cycles, &siblings));
                 let extra = match child_menu(*to, queries) {
-                    Some((_, menu, _)) => focus_deep(menu, queries),
+                    Some((menu_entity, menu, _)) => focus_deep(menu_entity, menu, queries),
                     None => Vec::new(),
                 };
                 let to = (extra, *to).into();
-                NavEvent::FocusChanged { to, from }
+                NavEvent::FocusChanged { to, from, request }
             }
         }
         FocusOn(new_to_focus) => {
             // assumption here is that there is a common ancestor
             // though nothing really breaks if there isn't
-            let mut from = queries.root_path(focused);
-            let mut to = queries.root_path(new_to_focus);
+            let (mut from, from_cycle) = queries.checked_root_path(focused);
+            let (mut to, to_cycle) = queries.checked_root_path(new_to_focus);
+            outcome.diagnostic = from_cycle.or(to_cycle).map(NavDiagnostic::Cycle);
             trim_common_tail(&mut from, &mut to);
             if from == to {
                 NavEvent::NoChanges { from, request }
             } else {
-                NavEvent::FocusChanged { from, to }
+                NavEvent::FocusChanged { from, to, request }
             }
         }
         Unlock => {
@@ -805,38 +1343,100 @@ fn resolve<STGY: MenuNavigationStrategy>(
     }
 }
 
+/// What [`resolve`] found besides the [`NavEvent`] it returns.
+#[derive(Default)]
+struct Outcome {
+    /// Set when a `Move` request doesn't change focus.
+    dead_end: Option<DeadEnd>,
+    /// Set when a `Cancel` request is sent from a focusable without
+    /// a parent menu, or in a menu without [`TreeMenu::focus_parent`].
+    root_cancelled: bool,
+    /// Set when the navigation tree is broken, for example
+    /// when it loops back on a menu.
+    diagnostic: Option<NavDiagnostic>,
+}
+
 /// Replaces [`MenuBuilder`]s with proper [`TreeMenu`]s.
+///
+/// Menus without focusables are inserted with no `active_child`,
+/// they become enterable once a [`Focusable`] is spawned in them.
+/// Menus forming a cycle are reported as [`NavDiagnostic`]s
+/// and kept as [`MenuBuilder`]s.
+/// `reported` avoids reporting the same menu every frame.
 pub(crate) fn insert_tree_menus(
     mut commands: Commands,
     builders: Query<(Entity, &MenuBuilder), With<MenuSetting>>,
     queries: NavQueries,
+    mut diagnostics: NavDiagnostics,
+    mut reported: Local<EntityHashSet>,
 ) {
     use FocusState::{Active, Focused, Prioritized};
     let mut inserts = Vec::new();
-    let no_focus_msg = "Within a menu built with MenuBuilder, there must be at least one entity \
-         with the Focusable component, none were found";
     for (entity, builder) in &builders {
-        let children = queries.children.focusables_of(entity);
-        let child = children
-            .iter()
-            .find_map(|e| {
-                let (_, focusable) = queries.focusables.get(*e).ok()?;
-                matches!(focusable.state, Prioritized | Active | Focused).then_some(e)
-            })
-            .unwrap_or_else(|| children.first().expect(no_focus_msg));
-        if let Ok(focus_parent) = builder.try_into() {
-            let menu = TreeMenu {
-                focus_parent,
-                active_child: *child,
-            };
-            inserts.push((entity, menu));
-            commands.entity(entity).remove::<MenuBuilder>();
-            debug!("Associated {entity:?} with a parent focusable.");
+        let Ok(focus_parent) = builder.try_into() else {
+            continue;
+        };
+        let prioritized = |e: &Entity| {
+            let state = queries.focusables.get(*e).map(|(_, f)| f.state);
+            matches!(state, Ok(Prioritized | Active | Focused))
+        };
+        let children = || queries.children.focusables_of(entity);
+        let child = children().find(prioritized).or_else(|| children().next());
+        let menu = TreeMenu {
+            focus_parent,
+            active_child: child,
+        };
+        inserts.push((entity, menu));
+    }
+    // Check for cycles, considering menus inserted this frame.
+    let is_menu = |e: &Entity| queries.menus.contains(*e) || inserts.iter().any(|i| i.0 == *e);
+    let menu_of = |focusable| queries.parents.iter_ancestors(focusable).find(is_menu);
+    let focus_parent_of = |menu| match inserts.iter().find(|i| i.0 == menu) {
+        Some((_, tree)) => tree.focus_parent,
+        None => queries.menus.get(menu).ok()?.1.focus_parent,
+    };
+    let menu_count = queries.menus.iter().len() + inserts.len();
+    let parent_menu = |menu| focus_parent_of(menu).and_then(menu_of);
+    let cycles: Vec<_> = inserts
+        .iter()
+        .map(|i| i.0)
+        .filter(|m| in_cycle(*m, menu_count, parent_menu))
+        .collect();
+    for &menu in &cycles {
+        if reported.insert(menu) {
+            diagnostics.report(NavDiagnostic::Cycle(menu));
         }
     }
+    inserts.retain(|(entity, _)| !cycles.contains(entity));
+    for (entity, tree) in &inserts {
+        set_menu_parent(&mut commands, *entity, tree.focus_parent);
+        reported.remove(entity);
+        commands.entity(*entity).remove::<MenuBuilder>();
+        debug!("Associated {entity:?} with a parent focusable.");
+    }
     commands.insert_batch(inserts);
 }
 
+/// Whether going up from `menu` with `parent_menu` leads back to `menu`.
+///
+/// `parent_menu` is the menu containing the `focus_parent` of a menu.
+/// At most `menu_count` menus are visited.
+pub(crate) fn in_cycle(
+    menu: Entity,
+    menu_count: usize,
+    parent_menu: impl Fn(Entity) -> Option<Entity>,
+) -> bool {
+    let mut current = menu;
+    for _ in 0..menu_count {
+        current = match parent_menu(current) {
+            Some(parent) if parent == menu => return true,
+            Some(parent) => parent,
+            None => return false,
+        };
+    }
+    false
+}
+
 /// System to set the first [`Focusable`] to [`FocusState::Focused`]
 /// when no navigation has been done yet.
 ///
@@ -846,47 +1446,363 @@ pub(crate) fn set_first_focused(
     has_focused: Query<(), With<Focused>>,
     mut queries: ParamSet<(NavQueries, MutQueries)>,
     mut events: EventWriter<NavEvent>,
+    mut focus_path: ResMut<FocusPath>,
+    mut diagnostics: NavDiagnostics,
 ) {
     if has_focused.is_empty() {
         if let Some(to_focus) = queries.p0().pick_first_focused() {
-            let breadcrumb = queries.p0().root_path(to_focus);
-            queries.p1().update_focus(&[], &breadcrumb);
+            let (breadcrumb, cycle) = queries.p0().checked_root_path(to_focus);
+            if let Some(menu) = cycle {
+                diagnostics.report(NavDiagnostic::Cycle(menu));
+            }
+            let menu_changes = queries.p0().menu_changes(&[], &breadcrumb);
+            // The previously focused entity was removed, reset what
+            // remains of its path.
+            let stale: Vec<_> = (focus_path.path().iter().copied())
+                .filter(|e| queries.p0().focusables.contains(*e) && !breadcrumb.contains(e))
+                .collect();
+            focus_path.set_if_neq(queries.p0().focus_path(to_focus));
+            let mut mut_queries = queries.p1();
+            mut_queries.update_focus(&stale, &breadcrumb);
+            mut_queries.trigger_focus_events(&[], &breadcrumb);
+            mut_queries.trigger_menu_events(menu_changes);
             events.write(NavEvent::InitiallyFocused(to_focus));
+        } else {
+            focus_path.set_if_neq(FocusPath::default());
+        }
+    }
+}
+
+/// The [`TreeMenu`] containing `entity`, if any.
+///
+/// This is `None` when `entity` is in a menu that isn't built yet,
+/// [`insert_tree_menus`] picks the `active_child` of new menus.
+fn hook_parent_menu(world: &DeferredWorld, mut entity: Entity) -> Option<Entity> {
+    loop {
+        entity = world.get::<ChildOf>(entity)?.parent();
+        if world.get::<TreeMenu>(entity).is_some() {
+            return Some(entity);
+        }
+        if world.get::<MenuSetting>(entity).is_some() {
+            return None;
+        }
+    }
+}
+
+/// Make `Prioritized` focusables added to an existing menu its `active_child`,
+/// unless focus is currently within the menu.
+///
+/// The first non-blocked focusable added to an empty menu
+/// becomes its `active_child`, making it enterable.
+fn on_add_focusable(world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    adopt_focusable(world, entity);
+}
+
+/// Run [`adopt_focusable`] for focusables spawned without a [`ChildOf`],
+/// once they are added to their menu.
+pub(crate) fn adopt_parented_focusables(
+    mut commands: Commands,
+    added: Query<Entity, (Added<Focusable>, Changed<ChildOf>)>,
+    parents: Query<&ChildOf>,
+    menus: Query<Ref<TreeMenu>>,
+) {
+    for entity in &added {
+        let menu = parents
+            .iter_ancestors(entity)
+            .find_map(|e| menus.get(e).ok());
+        // `insert_tree_menus` already picked the active child of new menus.
+        if menu.is_some_and(|menu| !menu.is_added()) {
+            commands.queue(move |world: &mut World| adopt_focusable(world.into(), entity));
+        }
+    }
+}
+
+/// Update the `active_child` of the menu containing the new focusable `entity`,
+/// see [`on_add_focusable`].
+///
+/// Running it several times for the same focusable changes nothing.
+fn adopt_focusable(mut world: DeferredWorld, entity: Entity) {
+    use FocusState::{Active, Blocked, Focused, Inert, Prioritized};
+    let Some(state) = world.get::<Focusable>(entity).map(|f| f.state) else {
+        return;
+    };
+    let Some(menu) = hook_parent_menu(&world, entity) else {
+        return;
+    };
+    let Some(previous) = world.get::<TreeMenu>(menu).unwrap().active_child else {
+        if state != Blocked {
+            world.get_mut::<TreeMenu>(menu).unwrap().active_child = Some(entity);
+        }
+        return;
+    };
+    if state != Prioritized {
+        return;
+    }
+    let previous_state = world.get::<Focusable>(previous).map(|f| f.state);
+    if matches!(previous_state, Some(Active | Focused)) || previous == entity {
+        return;
+    }
+    world.get_mut::<TreeMenu>(menu).unwrap().active_child = Some(entity);
+    if let Some(mut previous) = world.get_mut::<Focusable>(previous) {
+        if previous.state == Prioritized {
+            previous.state = Inert;
         }
     }
 }
 
+/// Pick a new `active_child` when the current one stops being a `Focusable`,
+/// see [`replace_active_child`].
+///
+/// A removed focused entity also loses [`Focused`],
+/// so that [`set_first_focused`] picks a new one.
+fn on_remove_focusable(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    if world.get::<Focused>(entity).is_some() {
+        world.commands().entity(entity).try_remove::<Focused>();
+    }
+    let Some(menu) = hook_parent_menu(&world, entity) else {
+        return;
+    };
+    if world.get::<TreeMenu>(menu).unwrap().active_child != Some(entity) {
+        return;
+    }
+    // Runs once `entity` isn't a `Focusable` anymore.
+    let mut commands = world.commands();
+    commands.run_system_cached_with(replace_active_child, (menu, entity));
+}
+
+/// Replace the `removed` `active_child` of `menu` with its first focusable.
+///
+/// The menu becomes empty if it has no other focusables.
+fn replace_active_child(
+    In((menu, removed)): In<(Entity, Entity)>,
+    mut menus: Query<&mut TreeMenu>,
+    mut queries: ParamSet<(ChildQueries, Query<&mut Focusable>)>,
+) {
+    let Ok(mut tree) = menus.get_mut(menu) else {
+        return;
+    };
+    if tree.active_child != Some(removed) {
+        return;
+    }
+    let new_active = queries.p0().focusables_of(menu).next();
+    tree.active_child = new_active;
+    let Some(new_active) = new_active else {
+        return;
+    };
+    if let Ok(mut new_active) = queries.p1().get_mut(new_active) {
+        if new_active.state == FocusState::Inert {
+            new_active.state = FocusState::Prioritized;
+        }
+    }
+}
+
+/// Repair the navigation tree after [`Focusable`]s or menus are
+/// despawned or moved in the hierarchy.
+///
+/// - Menus reachable from a removed focusable are relinked to the closest
+///   remaining focusable on the removed focusable's path to the root menu.
+///   If there is none, they become root menus.
+/// - Menus whose `active_child` was removed or moved out of them
+///   get a new `active_child`.
+/// - When the focused entity is moved to another menu,
+///   the [`FocusState::Active`] path leading to it is updated.
+///
+/// The focus path of each menu's `focus_parent` is stored in `chains`,
+/// since it can't be computed anymore once the focusables are removed.
+#[allow(clippy::type_complexity)]
+pub(crate) fn repair_nav_tree(
+    mut removed_focusables: RemovedComponents<Focusable>,
+    mut removed_menus: RemovedComponents<TreeMenu>,
+    focused: Query<Entity, With<Focused>>,
+    mut focus_path: ResMut<FocusPath>,
+    mut queries: ParamSet<(
+        NavQueries,
+        MutQueries,
+        Query<Entity, Added<TreeMenu>>,
+        NavMoves,
+    )>,
+    mut chains: Local<HashMap<Entity, Vec<Entity>>>,
+    mut diagnostics: NavDiagnostics,
+) {
+    let removed = removed_focusables.read().count() + removed_menus.read().count() != 0;
+    let changed = removed || queries.p3().any();
+    let added: Vec<_> = queries.p2().iter().collect();
+    if !changed && added.is_empty() {
+        return;
+    }
+    if changed {
+        let nav_queries = queries.p0();
+        let alive = |entity: &Entity| nav_queries.focusables.contains(*entity);
+        let mut repairs = Vec::new();
+        for (menu, tree, _) in &nav_queries.menus {
+            let focus_parent = match tree.focus_parent {
+                Some(parent) if !alive(&parent) => {
+                    let chain = chains.get(&menu).map_or(&[][..], |c| c.as_slice());
+                    chain.iter().find(|e| alive(e)).copied()
+                }
+                focus_parent => focus_parent,
+            };
+            let child = tree.active_child;
+            let in_menu = child.filter(alive).is_some_and(|child| {
+                let parent = nav_queries.parent_menu(child);
+                parent.is_some_and(|(m, ..)| m == menu)
+            });
+            let active_child = if in_menu {
+                child
+            } else {
+                nav_queries.children.focusables_of(menu).next()
+            };
+            if (focus_parent, active_child) != (tree.focus_parent, tree.active_child) {
+                repairs.push((menu, focus_parent, active_child));
+            }
+        }
+        let mut mut_queries = queries.p1();
+        for (menu, focus_parent, active_child) in repairs {
+            let Ok(mut tree) = mut_queries.menus.get_mut(menu) else {
+                continue;
+            };
+            debug!("Repaired {menu:?}: parent {focus_parent:?}, active child {active_child:?}");
+            tree.focus_parent = focus_parent;
+            set_menu_parent(&mut mut_queries.commands, menu, focus_parent);
+            tree.active_child = active_child;
+            let inert = |f: &Focusable| f.state() == FocusState::Inert;
+            let Some(active_child) = active_child else {
+                continue;
+            };
+            if mut_queries.focusables.get(active_child).is_ok_and(inert) {
+                mut_queries.set_entity_focus(active_child, FocusState::Prioritized);
+            }
+        }
+        // If the focused entity was removed, `set_first_focused` picks a new one.
+        if let Ok(focused) = focused.single() {
+            let nav_queries = queries.p0();
+            let alive = |entity: &Entity| nav_queries.focusables.contains(*entity);
+            let (mut to, cycle) = nav_queries.checked_root_path(focused);
+            if let Some(menu) = cycle {
+                diagnostics.report(NavDiagnostic::Cycle(menu));
+            }
+            let from: Vec<_> = focus_path.path().iter().copied().filter(alive).collect();
+            let new_path = nav_queries.focus_path(focused);
+            if let Ok(mut from) = NonEmpty::try_from(from) {
+                trim_common_tail(&mut from, &mut to);
+                if from != to {
+                    let mut mut_queries = queries.p1();
+                    mut_queries.update_focus(&from, &to);
+                    mut_queries.trigger_focus_events(&from, &to);
+                }
+            }
+            focus_path.set_if_neq(new_path);
+        }
+    }
+    let nav_queries = queries.p0();
+    let chain_of = |menu| {
+        let (_, tree, _) = nav_queries.menus.get(menu).ok()?;
+        Some(nav_queries.root_path(tree.focus_parent?).to_vec())
+    };
+    let updated: Vec<_> = if changed {
+        chains.clear();
+        nav_queries.menus.iter().map(|(menu, ..)| menu).collect()
+    } else {
+        // Only menus were added, update their chain and the chains
+        // going through focusables they contain.
+        let contained: EntityHashSet = (added.iter())
+            .flat_map(|menu| nav_queries.children.focusables_of(*menu))
+            .collect();
+        let stale = |chain: &Vec<Entity>| chain.iter().any(|e| contained.contains(e));
+        let stale = chains.iter().filter(|(_, chain)| stale(chain));
+        stale.map(|(menu, _)| *menu).chain(added).collect()
+    };
+    for menu in updated {
+        match chain_of(menu) {
+            Some(chain) => chains.insert(menu, chain),
+            None => chains.remove(&menu),
+        };
+    }
+}
+
+/// The components of focusables and menus, see [`NavMoves`].
+type NavComponents = (
+    Option<Ref<'static, Focusable>>,
+    Option<Ref<'static, MenuSetting>>,
+);
+
+/// Detect [`Focusable`]s and menus moved in the hierarchy, either directly
+/// or because one of their non-menu ancestors was moved.
+///
+/// Newly spawned focusables and menus are not considered moved.
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub(crate) struct NavMoves<'w, 's> {
+    moved:
+        Query<'w, 's, NavComponents, (Changed<ChildOf>, Or<(With<Focusable>, With<MenuSetting>)>)>,
+    moved_containers:
+        Query<'w, 's, Entity, (Changed<ChildOf>, Without<Focusable>, Without<MenuSetting>)>,
+    nav: Query<'w, 's, NavComponents>,
+    children: Query<'w, 's, &'static Children>,
+}
+impl NavMoves<'_, '_> {
+    fn any(&self) -> bool {
+        self.moved.iter().any(existing)
+            || self
+                .moved_containers
+                .iter()
+                .any(|e| self.contains_existing(e))
+    }
+    /// Whether `container` contains existing focusables or menus,
+    /// not looking inside of them.
+    fn contains_existing(&self, container: Entity) -> bool {
+        let Ok(children) = self.children.get(container) else {
+            return false;
+        };
+        children.iter().any(|&child| match self.nav.get(child) {
+            Ok((None, None)) | Err(_) => self.contains_existing(child),
+            Ok(components) => existing(components),
+        })
+    }
+}
+/// Whether these are the components of a focusable or a menu
+/// that wasn't spawned since the last run of the system.
+fn existing((focusable, menu): (Option<Ref<Focusable>>, Option<Ref<MenuSetting>>)) -> bool {
+    focusable.is_some_and(|f| !f.is_added()) || menu.is_some_and(|m| !m.is_added())
+}
+
 pub(crate) fn consistent_menu(
     updated_focusables: Query<(Entity, &Focusable), Changed<Focusable>>,
     children: ChildQueries,
-    mut menus: Query<(Entity, &mut TreeMenu)>,
+    parents: Query<&ChildOf>,
+    mut menus: Query<&mut TreeMenu>,
 ) {
     for (entity, updated) in &updated_focusables {
         if updated.state() != FocusState::Blocked {
             continue;
         }
-        for (menu_entity, mut menu) in &mut menus {
-            if menu.active_child != entity {
-                continue;
-            }
-            if let Some(new_active) = children.focusables_of(menu_entity).first().copied() {
-                menu.active_child = new_active;
-            }
-            // We found the unique menu that leads to the changed entity
-            // continue to check for next changed focusable.
-            break;
+        let Some(menu_entity) = parents.iter_ancestors(entity).find(|e| menus.contains(*e)) else {
+            continue;
+        };
+        let Ok(mut menu) = menus.get_mut(menu_entity) else {
+            continue;
+        };
+        if menu.active_child != Some(entity) {
+            continue;
+        }
+        if let Some(new_active) = children.focusables_of(menu_entity).next() {
+            menu.active_child = Some(new_active);
         }
     }
 }
 
 /// Listen to [`NavRequest`] and update the state of [`Focusable`] entities
 /// when relevant.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn listen_nav_requests<STGY: SystemParam>(
     mut queries: ParamSet<(NavQueries, MutQueries)>,
     mquery: StaticSystemParam<STGY>,
     mut lock: ResMut<NavLock>,
     mut requests: EventReader<NavRequest>,
     mut events: EventWriter<NavEvent>,
+    mut focus_path: ResMut<FocusPath>,
+    focused: Query<Entity, With<Focused>>,
+    mut diagnostics: NavDiagnostics,
 ) where
     for<'w, 's> SystemParamItem<'w, 's, STGY>: MenuNavigationStrategy,
 {
@@ -895,28 +1811,65 @@ pub(crate) fn listen_nav_requests<STGY: SystemParam>(
             NavRequest does nothing if \
             there isn't any navigation to do.";
 
-    // Cache focus result from previous iteration to avoid re-running costly `pick_first_focused`
-    let mut computed_focused = None;
+    // `Focused` is only updated once this system's commands are applied,
+    // keep track of focus changes caused by previous requests of this frame.
+    let mut computed_focused = focused.single().ok();
+    if let Some(entity) = computed_focused.filter(|e| !queries.p0().focusables.contains(*e)) {
+        diagnostics.report(NavDiagnostic::MissingFocusable(entity));
+        computed_focused = queries.p0().pick_first_focused();
+    }
     for request in requests.read() {
-        if lock.is_locked() && *request != NavRequest::Unlock {
+        let locked = lock.is_locked() && *request != NavRequest::Unlock;
+        if locked && !matches!(request, NavRequest::Move(_)) {
             continue;
         }
-        // We use `pick_first_focused` instead of `Focused` component for first
-        // iteration because `set_first_focused` just before `listen_nav_request`
-        // without a command flush in-between.
-        let picked = || queries.p0().pick_first_focused();
-        let focused = match computed_focused.or_else(picked) {
+        let focused = match computed_focused {
             Some(focused) => focused,
+            None if locked => continue,
             None => {
                 warn!(no_focused);
                 return;
             }
         };
+        if locked {
+            if let NavRequest::Move(direction) = *request {
+                let menu = queries.p0().parent_menu(focused).map(|(menu, ..)| menu);
+                let reason = DeadEndReason::Locked;
+                let dead_end = DeadEnd {
+                    direction,
+                    menu,
+                    reason,
+                };
+                queries.p1().trigger_dead_end(focused, dead_end);
+            }
+            continue;
+        }
         let from = Vec::new();
-        let event = resolve(focused, *request, &queries.p0(), &mut lock, from, &*mquery);
-        if let NavEvent::FocusChanged { to, from } = &event {
+        let mut outcome = Outcome::default();
+        let event = resolve(
+            focused,
+            *request,
+            &queries.p0(),
+            &mut lock,
+            from,
+            &*mquery,
+            &mut outcome,
+        );
+        if let Some(diagnostic) = outcome.diagnostic.take() {
+            diagnostics.report(diagnostic);
+        }
+        queries.p1().trigger_outcome(focused, outcome);
+        let mut menu_changes = None;
+        if let NavEvent::FocusChanged { to, from, .. } = &event {
+            menu_changes = Some(queries.p0().menu_changes(from, to));
+            focus_path.set_if_neq(queries.p0().focus_path(*to.first()));
             computed_focused = Some(queries.p1().update_focus(from, to));
         };
+        let mut mut_queries = queries.p1();
+        mut_queries.trigger_events(&event);
+        if let Some(menu_changes) = menu_changes {
+            mut_queries.trigger_menu_events(menu_changes);
+        }
         events.write(event);
     }
 }
@@ -926,10 +1879,11 @@ fn child_menu<'a>(
     focusable: Entity,
     queries: &'a NavQueries,
 ) -> Option<(Entity, &'a TreeMenu, &'a MenuSetting)> {
-    queries
-        .menus
+    let child_menus = queries.child_menus.get(focusable).ok()?;
+    child_menus
+        .0
         .iter()
-        .find(|e| e.1.focus_parent == Some(focusable))
+        .find_map(|menu| queries.menus.get(*menu).ok())
 }
 
 /// The [`TreeMenu`] containing `focusable`, if any.
@@ -946,21 +1900,22 @@ pub(crate) fn parent_menu(
 
 impl ChildQueries<'_, '_> {
     /// All sibling [`Focusable`]s within a single [`TreeMenu`].
-    pub(crate) fn focusables_of(&self, menu: Entity) -> Vec<Entity> {
+    ///
+    /// Uses [`MenuFocusables`] when `menu` is cached.
+    pub(crate) fn focusables_of(&self, menu: Entity) -> impl Iterator<Item = Entity> + '_ {
         use FocusState::Blocked;
-        let is_focusable = |e: &&_| self.is_focusable.get(**e).is_ok_and(|f| f.state != Blocked);
-        match self.children.get(menu) {
-            Ok(direct_children) => {
-                let focusables = direct_children.iter().filter(is_focusable).cloned();
-                let transitive_focusables = direct_children
-                    .iter()
-                    .filter(|e| !self.is_focusable.contains(**e))
-                    .filter(|e| !self.is_menu.contains(**e))
-                    .flat_map(|e| self.focusables_of(*e));
-                focusables.chain(transitive_focusables).collect()
-            }
-            Err(_) => Vec::new(),
-        }
+        let is_focusable = move |e: &Entity| {
+            let unblocked = self.is_focusable.get(*e).is_ok_and(|f| f.state != Blocked);
+            unblocked && !self.hidden.is_hidden(*e)
+        };
+        let cached = self.cache.menus.get(&menu);
+        let uncached = cached
+            .is_none()
+            .then(|| self.hierarchy.all_focusables(menu));
+        let focusables = cached.into_iter().flatten().copied();
+        focusables
+            .chain(uncached.into_iter().flatten())
+            .filter(is_focusable)
     }
 }
 
@@ -987,13 +1942,21 @@ fn trim_common_tail<T: PartialEq>(v1: &mut NonEmpty<T>, v2: &mut NonEmpty<T>) {
 }
 
 /// Navigate downward the menu hierarchy, traversing all prioritized children.
-fn focus_deep<'a>(mut menu: &'a TreeMenu, queries: &'a NavQueries) -> Vec<Entity> {
+///
+/// Stops at the first empty menu.
+fn focus_deep<'a>(
+    mut menu_entity: Entity,
+    mut menu: &'a TreeMenu,
+    queries: &'a NavQueries,
+) -> Vec<Entity> {
     let mut ret = Vec::with_capacity(4);
     loop {
-        let last = menu.active_child;
+        let Some(last) = queries.entered_child(menu_entity, menu) else {
+            return ret;
+        };
         ret.insert(0, last);
-        menu = match child_menu(last, queries) {
-            Some((_, menu, _)) => menu,
+        (menu_entity, menu) = match child_menu(last, queries) {
+            Some((entity, menu, _)) => (entity, menu),
             None => return ret,
         };
     }
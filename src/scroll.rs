@@ -0,0 +1,185 @@
+//! Keep the focused element visible within `bevy_ui` scroll containers.
+//!
+//! Add the [`ScrollToFocusedPlugin`] to your app, and whenever a [`Focusable`]
+//! nested within a [`Node`] with [`OverflowAxis::Scroll`] becomes [`Focused`],
+//! the [`ScrollPosition`] of its scrollable ancestors is updated so that
+//! the focused node is visible.
+//!
+//! [`Focusable`]: crate::prelude::Focusable
+use bevy::prelude::*;
+
+use crate::{resolve::Focused, NavRequestSystem};
+
+/// Where to place the focused element within its scroll container.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub enum ScrollAlign {
+    /// Scroll as little as possible to make the focused element visible.
+    #[default]
+    Nearest,
+
+    /// Scroll so that the focused element is at the center of the container.
+    Center,
+}
+
+/// Control how [`scroll_to_focused`] updates [`ScrollPosition`]s.
+#[derive(Default, Debug, Clone, Copy, Resource)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Resource))]
+pub struct ScrollToFocused {
+    /// Space to keep between the focused element and the edges
+    /// of its scroll container, in logical pixels.
+    ///
+    /// Only used with [`ScrollAlign::Nearest`].
+    pub margin: Vec2,
+    /// Where to place the focused element within its scroll container.
+    pub align: ScrollAlign,
+}
+
+/// Scroll the scrollable ancestors of newly [`Focused`] nodes
+/// so that the focused node is visible.
+///
+/// The computation uses the layout of the previous frame,
+/// so the focused node is only visible once the layout is updated.
+pub fn scroll_to_focused(
+    settings: Res<ScrollToFocused>,
+    focused: Query<Entity, Added<Focused>>,
+    parents: Query<&ChildOf>,
+    nodes: Query<(&ComputedNode, &GlobalTransform)>,
+    mut containers: Query<(&Node, &ComputedNode, &GlobalTransform, &mut ScrollPosition)>,
+) {
+    for focused in &focused {
+        let Ok((node, transform)) = nodes.get(focused) else {
+            continue;
+        };
+        let center = transform.translation().truncate();
+        let (mut min, mut max) = (center - node.size() / 2.0, center + node.size() / 2.0);
+
+        for ancestor in parents.iter_ancestors(focused) {
+            let Ok((style, container, transform, mut scroll)) = containers.get_mut(ancestor) else {
+                continue;
+            };
+            let scrolls = BVec2::new(
+                style.overflow.x == OverflowAxis::Scroll,
+                style.overflow.y == OverflowAxis::Scroll,
+            );
+            if !scrolls.any() {
+                continue;
+            }
+            let inset = container.content_inset();
+            let center = transform.translation().truncate();
+            let half_size = container.size() / 2.0;
+            let visible_min = center - half_size + Vec2::new(inset.left, inset.top);
+            let visible_max = center + half_size - Vec2::new(inset.right, inset.bottom);
+            let margin = settings.margin / container.inverse_scale_factor();
+
+            let axis_delta = |axis: usize| match settings.align {
+                ScrollAlign::Center => {
+                    (min[axis] + max[axis] - visible_min[axis] - visible_max[axis]) / 2.0
+                }
+                ScrollAlign::Nearest => {
+                    let before = min[axis] - visible_min[axis] - margin[axis];
+                    let after = max[axis] - visible_max[axis] + margin[axis];
+                    if before < 0.0 {
+                        before
+                    } else if after > 0.0 {
+                        after.min(before)
+                    } else {
+                        0.0
+                    }
+                }
+            };
+            let delta = Vec2::select(scrolls, Vec2::new(axis_delta(0), axis_delta(1)), Vec2::ZERO);
+            if delta == Vec2::ZERO {
+                continue;
+            }
+            let offset = Vec2::from(&*scroll) + delta * container.inverse_scale_factor();
+            *scroll = ScrollPosition::from(offset.max(Vec2::ZERO));
+
+            // The focused node moves in the opposite direction of the scroll,
+            // outer containers should reveal it at its new position.
+            min -= delta;
+            max -= delta;
+        }
+    }
+}
+
+/// Scroll `bevy_ui` scroll containers to keep the focused element visible.
+///
+/// Add it to your app with `.add_plugins(ScrollToFocusedPlugin)`,
+/// and customize the behavior with the [`ScrollToFocused`] resource.
+pub struct ScrollToFocusedPlugin;
+impl Plugin for ScrollToFocusedPlugin {
+    fn build(&self, app: &mut App) {
+        #[cfg(feature = "bevy_reflect")]
+        app.register_type::<ScrollAlign>()
+            .register_type::<ScrollToFocused>();
+
+        app.init_resource::<ScrollToFocused>()
+            .add_systems(Update, scroll_to_focused.after(NavRequestSystem));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    /// Scroll a 100×100 vertical scroll container centered at `(50, 50)`
+    /// already scrolled by `offset`, to reveal a 100×20 focused node at `y`.
+    fn scrolled(settings: ScrollToFocused, offset: f32, y: f32) -> f32 {
+        let mut world = World::new();
+        world.insert_resource(settings);
+        let size = |x, y| ComputedNode {
+            size: Vec2::new(x, y),
+            ..default()
+        };
+        let container = Node {
+            overflow: Overflow::scroll_y(),
+            ..default()
+        };
+        let scroll = ScrollPosition {
+            offset_y: offset,
+            ..default()
+        };
+        let at = |y| GlobalTransform::from_xyz(50.0, y, 0.0);
+        let container = (container, size(100.0, 100.0), at(50.0), scroll);
+        let container = world.spawn(container).id();
+        world.spawn((size(100.0, 20.0), at(y), Focused, ChildOf(container)));
+        world.run_system_once(scroll_to_focused).unwrap();
+        world.get::<ScrollPosition>(container).unwrap().offset_y
+    }
+
+    #[test]
+    fn scroll_nearest() {
+        let nearest = ScrollToFocused::default();
+        // Already visible
+        assert_eq!(scrolled(nearest, 0.0, 50.0), 0.0);
+        // Below, the bottom of the focused node aligns with the container's
+        assert_eq!(scrolled(nearest, 0.0, 150.0), 60.0);
+        // Above, the top of the focused node aligns with the container's
+        assert_eq!(scrolled(nearest, 100.0, -30.0), 60.0);
+        // Scroll position can't be negative
+        assert_eq!(scrolled(nearest, 10.0, -30.0), 0.0);
+
+        let margin = ScrollToFocused {
+            margin: Vec2::splat(10.0),
+            ..nearest
+        };
+        assert_eq!(scrolled(margin, 0.0, 150.0), 70.0);
+        assert_eq!(scrolled(margin, 0.0, 15.0), 0.0);
+        assert_eq!(scrolled(margin, 100.0, -30.0), 50.0);
+    }
+
+    #[test]
+    fn scroll_center() {
+        let center = ScrollToFocused {
+            align: ScrollAlign::Center,
+            ..default()
+        };
+        assert_eq!(scrolled(center, 0.0, 50.0), 0.0);
+        assert_eq!(scrolled(center, 0.0, 150.0), 100.0);
+        assert_eq!(scrolled(center, 0.0, 70.0), 20.0);
+        assert_eq!(scrolled(center, 100.0, 30.0), 80.0);
+    }
+}
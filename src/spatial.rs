@@ -0,0 +1,296 @@
+//! A uniform grid of [`Focusable`]s, to avoid iterating all of them
+//! when looking for focusables at or around a position.
+//!
+//! The [`FocusableGrid`] resource is used to:
+//! * Move between focusables outside of menus with strategies that pick
+//!   the closest focusable, see [`MenuNavigationStrategy::picks_closest`].
+//! * Find the focusable under the mouse cursor in
+//!   [`generic_default_mouse_input`] and [`generic_default_mouse_hover`].
+//!
+//! The [`NavigationPlugin`] keeps a `FocusableGrid<ComputedNode>` up to date.
+//! If you use the generic mouse systems with another [`ScreenSize`] component,
+//! add a [`FocusableGridPlugin`] for it.
+//!
+//! [`Focusable`]: crate::prelude::Focusable
+//! [`MenuNavigationStrategy::picks_closest`]: crate::prelude::MenuNavigationStrategy::picks_closest
+//! [`generic_default_mouse_input`]: crate::systems::generic_default_mouse_input
+//! [`generic_default_mouse_hover`]: crate::systems::generic_default_mouse_hover
+//! [`NavigationPlugin`]: crate::prelude::NavigationPlugin
+use std::marker::PhantomData;
+
+use bevy::ecs::entity::EntityHashMap;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+
+use crate::{events::Direction, resolve::Focusable, systems::ScreenSize};
+
+#[derive(Debug, Clone, Copy)]
+struct GridEntry {
+    center: Vec2,
+    min: Vec2,
+    max: Vec2,
+    cells: IRect,
+}
+
+/// The [`Focusable`]s with a [`GlobalTransform`], indexed by position.
+///
+/// Each focusable is stored in all the cells its `T` [`ScreenSize`] overlaps,
+/// focusables without a `T` component are treated as points.
+///
+/// The grid is updated in `PostUpdate`, after transform propagation.
+/// Focusables spawned or moved during a frame are only found
+/// at their new position in the following frames.
+///
+/// See the [module documentation](self) for details.
+#[derive(Resource)]
+pub struct FocusableGrid<T = ComputedNode> {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<Entity>>,
+    entries: EntityHashMap<GridEntry>,
+    bounds: Option<IRect>,
+    _size: PhantomData<fn() -> T>,
+}
+impl<T> Default for FocusableGrid<T> {
+    fn default() -> Self {
+        FocusableGrid::new(64.0)
+    }
+}
+impl<T> FocusableGrid<T> {
+    /// Create an empty grid with cells of `cell_size` world units.
+    ///
+    /// Insert it before adding the [`NavigationPlugin`] to change
+    /// the default cell size of `64.0`.
+    ///
+    /// [`NavigationPlugin`]: crate::prelude::NavigationPlugin
+    pub fn new(cell_size: f32) -> Self {
+        FocusableGrid {
+            cell_size,
+            cells: HashMap::default(),
+            entries: EntityHashMap::default(),
+            bounds: None,
+            _size: PhantomData,
+        }
+    }
+    /// How many focusables are in the grid.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    /// Whether the grid is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+    /// The position of `entity` in the grid, if it is in it.
+    pub fn position(&self, entity: Entity) -> Option<Vec2> {
+        self.entries.get(&entity).map(|entry| entry.center)
+    }
+    fn cell(&self, position: Vec2) -> IVec2 {
+        (position / self.cell_size).floor().as_ivec2()
+    }
+    fn insert(&mut self, entity: Entity, center: Vec2, size: Vec2) {
+        self.remove(entity);
+        let (min, max) = (center - size / 2.0, center + size / 2.0);
+        let cells = IRect::from_corners(self.cell(min), self.cell(max));
+        for x in cells.min.x..=cells.max.x {
+            for y in cells.min.y..=cells.max.y {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(entity);
+            }
+        }
+        self.bounds = Some(self.bounds.map_or(cells, |b| b.union(cells)));
+        let entry = GridEntry {
+            center,
+            min,
+            max,
+            cells,
+        };
+        self.entries.insert(entity, entry);
+    }
+    fn remove(&mut self, entity: Entity) {
+        let Some(entry) = self.entries.remove(&entity) else {
+            return;
+        };
+        for x in entry.cells.min.x..=entry.cells.max.x {
+            for y in entry.cells.min.y..=entry.cells.max.y {
+                let cell = IVec2::new(x, y);
+                let Some(entities) = self.cells.get_mut(&cell) else {
+                    continue;
+                };
+                entities.retain(|e| *e != entity);
+                if entities.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        }
+        if self.entries.is_empty() {
+            self.bounds = None;
+        }
+    }
+
+    /// The focusables whose area contains `point`.
+    pub fn at(&self, point: Vec2) -> impl Iterator<Item = Entity> + '_ {
+        let entities = self.cells.get(&self.cell(point)).into_iter().flatten();
+        entities.copied().filter(move |e| {
+            let entry = &self.entries[e];
+            (entry.min.x..entry.max.x).contains(&point.x)
+                && (entry.min.y..entry.max.y).contains(&point.y)
+        })
+    }
+
+    /// The focusable closest to `from` in `direction`, for which `filter` is `true`.
+    ///
+    /// Positions are the centers of focusables,
+    /// see [`Direction::is_in`] for what is in `direction`.
+    pub fn closest_in(
+        &self,
+        from: Vec2,
+        direction: Direction,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Option<Entity> {
+        let bounds = self.bounds?;
+        let origin = self.cell(from);
+        // How many cells `offset` goes in `direction`.
+        let ahead = |offset: IVec2| match direction {
+            Direction::North => -offset.y,
+            Direction::South => offset.y,
+            Direction::East => offset.x,
+            Direction::West => -offset.x,
+        };
+        // Focusables in `direction` are at most one cell further sideways
+        // than ahead, so in rings up to one past the bounds on that side.
+        let max_ring = ahead(bounds.max - origin).max(ahead(bounds.min - origin)) + 1;
+        let mut closest: Option<(f32, Entity)> = None;
+        for ring in 0..=max_ring {
+            // Focusables centered in this ring or later ones
+            // are at least `(ring - 1) * cell_size` away.
+            let ring_distance = (ring - 1).max(0) as f32 * self.cell_size;
+            if closest.is_some_and(|(d, _)| d <= ring_distance * ring_distance) {
+                break;
+            }
+            for cell in ring_cells(origin, ring).filter(|cell| ahead(*cell - origin) >= 0) {
+                let Some(entities) = self.cells.get(&cell) else {
+                    continue;
+                };
+                for &entity in entities {
+                    let center = self.entries[&entity].center;
+                    if !direction.is_in(from, center) || !filter(entity) {
+                        continue;
+                    }
+                    let distance = from.distance_squared(center);
+                    if closest.is_none_or(|(d, _)| distance < d) {
+                        closest = Some((distance, entity));
+                    }
+                }
+            }
+        }
+        closest.map(|(_, entity)| entity)
+    }
+}
+
+/// The cells at exactly `ring` cells (in chebyshev distance) from `origin`.
+fn ring_cells(origin: IVec2, ring: i32) -> impl Iterator<Item = IVec2> {
+    let side = -ring..=ring;
+    let horizontal = side
+        .clone()
+        .flat_map(move |x| [IVec2::new(x, -ring), IVec2::new(x, ring)]);
+    let vertical = (-ring + 1..ring).flat_map(move |y| [IVec2::new(-ring, y), IVec2::new(ring, y)]);
+    let cells = horizontal
+        .chain(vertical)
+        .map(move |offset| origin + offset);
+    // The ring 0 is a single cell, listed twice by `horizontal`.
+    cells.take(if ring == 0 { 1 } else { usize::MAX })
+}
+
+/// Update the [`FocusableGrid`] of `T` with moved, resized,
+/// added and removed [`Focusable`]s.
+#[allow(clippy::type_complexity)]
+pub fn update_focusable_grid<T: ScreenSize + Component>(
+    mut grid: ResMut<FocusableGrid<T>>,
+    changed: Query<
+        (Entity, &GlobalTransform, Option<&T>),
+        (
+            With<Focusable>,
+            Or<(Changed<GlobalTransform>, Changed<T>, Added<Focusable>)>,
+        ),
+    >,
+    mut removed: RemovedComponents<Focusable>,
+) {
+    for entity in removed.read() {
+        grid.remove(entity);
+    }
+    for (entity, transform, size) in &changed {
+        let size = size.map_or(Vec2::ZERO, T::size);
+        grid.insert(entity, transform.translation().xy(), size);
+    }
+}
+
+/// Keep a [`FocusableGrid`] of `T` up to date.
+///
+/// This is not needed for [`ComputedNode`],
+/// the [`NavigationPlugin`] already keeps its grid up to date.
+///
+/// [`NavigationPlugin`]: crate::prelude::NavigationPlugin
+pub struct FocusableGridPlugin<T>(PhantomData<fn() -> T>);
+impl<T> Default for FocusableGridPlugin<T> {
+    fn default() -> Self {
+        FocusableGridPlugin(PhantomData)
+    }
+}
+impl<T: ScreenSize + Component> Plugin for FocusableGridPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FocusableGrid<T>>().add_systems(
+            PostUpdate,
+            update_focusable_grid::<T>.after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closest_in_direction() {
+        use Direction::*;
+        let mut grid = FocusableGrid::<ComputedNode>::new(10.0);
+        let entity = Entity::from_raw;
+        grid.insert(entity(0), Vec2::ZERO, Vec2::ZERO);
+        grid.insert(entity(1), Vec2::new(25.0, 1.0), Vec2::ZERO);
+        grid.insert(entity(2), Vec2::new(95.0, -3.0), Vec2::ZERO);
+        grid.insert(entity(3), Vec2::new(-4.0, 42.0), Vec2::ZERO);
+
+        let closest = |grid: &FocusableGrid, dir, filter: fn(Entity) -> bool| {
+            grid.closest_in(Vec2::ZERO, dir, filter)
+        };
+        assert_eq!(closest(&grid, East, |_| true), Some(entity(1)));
+        assert_eq!(closest(&grid, East, |e| e.index() != 1), Some(entity(2)));
+        assert_eq!(closest(&grid, South, |_| true), Some(entity(3)));
+        assert_eq!(closest(&grid, West, |_| true), None);
+
+        grid.remove(entity(1));
+        assert_eq!(closest(&grid, East, |_| true), Some(entity(2)));
+
+        // In the same column, but further sideways than ahead
+        let mut grid = FocusableGrid::<ComputedNode>::new(10.0);
+        grid.insert(entity(0), Vec2::new(9.9, 10.1), Vec2::ZERO);
+        let from = Vec2::new(0.1, 9.9);
+        assert_eq!(grid.closest_in(from, East, |_| true), Some(entity(0)));
+        assert_eq!(grid.closest_in(from, West, |_| true), None);
+    }
+
+    #[test]
+    fn at_point() {
+        let mut grid = FocusableGrid::<ComputedNode>::new(10.0);
+        let (big, small) = (Entity::from_raw(0), Entity::from_raw(1));
+        grid.insert(big, Vec2::ZERO, Vec2::splat(40.0));
+        grid.insert(small, Vec2::new(15.0, 15.0), Vec2::splat(4.0));
+
+        let at = |grid: &FocusableGrid, point| grid.at(point).collect::<Vec<_>>();
+        assert_eq!(at(&grid, Vec2::new(-19.0, 19.0)), [big]);
+        assert_eq!(at(&grid, Vec2::new(16.0, 14.0)), [big, small]);
+        assert_eq!(at(&grid, Vec2::new(25.0, 0.0)), []);
+
+        grid.insert(big, Vec2::new(100.0, 0.0), Vec2::splat(40.0));
+        assert_eq!(at(&grid, Vec2::new(16.0, 14.0)), [small]);
+        assert_eq!(at(&grid, Vec2::new(110.0, 0.0)), [big]);
+    }
+}
@@ -0,0 +1,147 @@
+//! Declarative styling of [`Focusable`]s according to their [`FocusState`].
+//!
+//! Add a [`FocusStyle`] component to a `bevy_ui` node with a [`Focusable`],
+//! and its [`BackgroundColor`], [`BorderColor`] and [`Outline`] colors
+//! will be updated whenever its [`FocusState`] changes.
+use bevy::prelude::*;
+
+use crate::resolve::{FocusState, Focusable};
+
+/// The colors of a `bevy_ui` node in a given [`FocusState`].
+///
+/// A `None` color leaves the corresponding component untouched.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub struct StateStyle {
+    /// The [`BackgroundColor`] of the node.
+    pub background: Option<Color>,
+    /// The [`BorderColor`] of the node.
+    pub border: Option<Color>,
+    /// The color of the node's [`Outline`].
+    pub outline: Option<Color>,
+}
+impl StateStyle {
+    /// A `StateStyle` that doesn't change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Set the [`background`](Self::background) color.
+    pub fn background(mut self, color: impl Into<Color>) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+    /// Set the [`border`](Self::border) color.
+    pub fn border(mut self, color: impl Into<Color>) -> Self {
+        self.border = Some(color.into());
+        self
+    }
+    /// Set the [`outline`](Self::outline) color.
+    pub fn outline(mut self, color: impl Into<Color>) -> Self {
+        self.outline = Some(color.into());
+        self
+    }
+}
+
+/// Style this [`Focusable`] according to its [`FocusState`].
+///
+/// The style is applied by the navigation plugin
+/// after the [`NavRequestSystem`](crate::NavRequestSystem).
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::{color::palettes::css::*, prelude::*};
+/// # use bevy_alt_ui_navigation_lite::prelude::*;
+/// use bevy_alt_ui_navigation_lite::style::{FocusStyle, StateStyle};
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Button,
+///         Focusable::new(),
+///         FocusStyle::new(StateStyle::new().background(DARK_GRAY))
+///             .with(FocusState::Focused, StateStyle::new().background(ORANGE_RED)),
+///     ));
+/// }
+/// ```
+#[derive(Component, Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Component))]
+pub struct FocusStyle {
+    /// Style in [`FocusState::Blocked`].
+    pub blocked: StateStyle,
+    /// Style in [`FocusState::Active`].
+    pub active: StateStyle,
+    /// Style in [`FocusState::Prioritized`].
+    pub prioritized: StateStyle,
+    /// Style in [`FocusState::Focused`].
+    pub focused: StateStyle,
+    /// Style in [`FocusState::Inert`].
+    pub inert: StateStyle,
+}
+impl FocusStyle {
+    /// A `FocusStyle` using `style` for all [`FocusState`]s.
+    ///
+    /// Use [`FocusStyle::with`] to customize specific states.
+    pub fn new(style: StateStyle) -> Self {
+        FocusStyle {
+            blocked: style,
+            active: style,
+            prioritized: style,
+            focused: style,
+            inert: style,
+        }
+    }
+    /// Use `style` when in the given `state`.
+    pub fn with(mut self, state: FocusState, style: StateStyle) -> Self {
+        *self.get_mut(state) = style;
+        self
+    }
+    /// The style to use in the given `state`.
+    pub fn get(&self, state: FocusState) -> &StateStyle {
+        match state {
+            FocusState::Blocked => &self.blocked,
+            FocusState::Active => &self.active,
+            FocusState::Prioritized => &self.prioritized,
+            FocusState::Focused => &self.focused,
+            FocusState::Inert => &self.inert,
+        }
+    }
+    fn get_mut(&mut self, state: FocusState) -> &mut StateStyle {
+        match state {
+            FocusState::Blocked => &mut self.blocked,
+            FocusState::Active => &mut self.active,
+            FocusState::Prioritized => &mut self.prioritized,
+            FocusState::Focused => &mut self.focused,
+            FocusState::Inert => &mut self.inert,
+        }
+    }
+}
+
+/// Update the colors of [`FocusStyle`] entities when their state changes.
+#[allow(clippy::type_complexity)]
+pub(crate) fn apply_focus_style(
+    mut styled: Query<
+        (
+            &FocusStyle,
+            &Focusable,
+            Option<&mut BackgroundColor>,
+            Option<&mut BorderColor>,
+            Option<&mut Outline>,
+        ),
+        Or<(Changed<Focusable>, Changed<FocusStyle>)>,
+    >,
+) {
+    for (style, focusable, background, border, outline) in &mut styled {
+        let style = style.get(focusable.state());
+        if let (Some(color), Some(mut background)) = (style.background, background) {
+            background.set_if_neq(BackgroundColor(color));
+        }
+        if let (Some(color), Some(mut border)) = (style.border, border) {
+            border.set_if_neq(BorderColor(color));
+        }
+        if let (Some(color), Some(mut outline)) = (style.outline, outline) {
+            if outline.color != color {
+                outline.color = color;
+            }
+        }
+    }
+}
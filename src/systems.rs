@@ -1,9 +1,11 @@
 //! System for the navigation tree and default input systems to get started.
 use crate::{
-    events::{Direction, NavRequest, ScopeDirection},
-    resolve::{FocusState, Focusable, Focused, ScreenBoundaries},
+    events::{Direction, NavDiagnostic, NavRequest, ScopeDirection},
+    resolve::{FocusState, Focusable, Focused, HiddenQueries, NavDiagnostics, ScreenBoundaries},
+    spatial::FocusableGrid,
 };
 
+use bevy::ecs::entity::EntityHashSet;
 use bevy::math::FloatOrd;
 use bevy::window::PrimaryWindow;
 #[cfg(feature = "bevy_reflect")]
@@ -74,6 +76,12 @@ pub struct InputMapping {
     pub mouse_action: MouseButton,
     /// Whether mouse hover gives focus to [`Focusable`] elements.
     pub focus_follows_mouse: bool,
+    /// Which edge of the action input sends [`NavRequest::Action`].
+    ///
+    /// When `None`, each device uses its own default:
+    /// [`ActionEdge::Press`] for keyboard and gamepad,
+    /// [`ActionEdge::Release`] for mouse.
+    pub action_edge: Option<ActionEdge>,
 }
 impl Default for InputMapping {
     fn default() -> Self {
@@ -108,6 +116,60 @@ impl Default for InputMapping {
             key_free: KeyCode::Escape,
             mouse_action: MouseButton::Left,
             focus_follows_mouse: false,
+            action_edge: None,
+        }
+    }
+}
+
+/// When to send [`NavRequest::Action`], see [`InputMapping::action_edge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+pub enum ActionEdge {
+    /// Activate as soon as the action input is pressed.
+    Press,
+    /// Activate when the action input is released,
+    /// if the pressed [`Focusable`] is still [`Pressed`].
+    Release,
+}
+
+/// The [`Focusable`] being pressed.
+///
+/// It is added to the focused `Focusable` when the action input of any device
+/// is pressed, and removed when it is released.
+/// It is also removed when the `Focusable` loses focus,
+/// or when the mouse cursor leaves it, cancelling the press:
+/// with [`ActionEdge::Release`], no [`NavRequest::Action`] is sent
+/// when releasing a cancelled press.
+///
+/// This marker is maintained by the default input systems.
+#[derive(Component)]
+#[non_exhaustive]
+pub struct Pressed;
+
+/// Update [`Pressed`] and send [`NavRequest::Action`]
+/// according to the `edge` for a device's action input.
+fn track_press(
+    commands: &mut Commands,
+    (just_pressed, just_released): (bool, bool),
+    edge: ActionEdge,
+    focused: Entity,
+    is_pressed: &Query<(), With<Pressed>>,
+    pressing: &mut Option<Entity>,
+    nav_cmds: &mut EventWriter<NavRequest>,
+) {
+    if just_pressed {
+        commands.entity(focused).try_insert(Pressed);
+        *pressing = Some(focused);
+        if edge == ActionEdge::Press {
+            nav_cmds.write(NavRequest::Action);
+        }
+    }
+    if just_released {
+        if let Some(released) = pressing.take() {
+            commands.entity(released).try_remove::<Pressed>();
+            if edge == ActionEdge::Release && is_pressed.contains(released) {
+                nav_cmds.write(NavRequest::Action);
+            }
         }
     }
 }
@@ -126,20 +188,25 @@ macro_rules! mapping {
 /// You may however need to customize the behavior of this system (typically
 /// when integrating in the game) in this case, you should write your own
 /// system that sends [`NavRequest`] events
+#[allow(clippy::too_many_arguments)]
 pub fn default_gamepad_input(
+    mut commands: Commands,
     mut nav_cmds: EventWriter<NavRequest>,
-    has_focused: Query<(), With<Focused>>,
+    has_focused: Query<Entity, With<Focused>>,
+    is_pressed: Query<(), With<Pressed>>,
     input_mapping: Res<InputMapping>,
     gamepads: Query<(Entity, &Gamepad)>,
     mut ui_input_status: Local<bool>,
+    mut pressing: Local<Option<Entity>>,
 ) {
     use Direction::*;
-    use NavRequest::{Action, Cancel, Move, ScopeMove, Unlock};
+    use NavRequest::{Cancel, Move, ScopeMove, Unlock};
 
-    if has_focused.is_empty() {
+    let Ok(focused) = has_focused.single() else {
         // Do not compute navigation if there is no focus to change
         return;
-    }
+    };
+    let edge = input_mapping.action_edge.unwrap_or(ActionEdge::Press);
 
     for (entity, gamepad) in &gamepads {
         if !input_mapping.gamepads.is_empty() && !input_mapping.gamepads.contains(&entity) {
@@ -168,8 +235,17 @@ pub fn default_gamepad_input(
             *ui_input_status = false;
         }
 
+        let action = input_mapping.action_button;
+        track_press(
+            &mut commands,
+            (gamepad.just_pressed(action), gamepad.just_released(action)),
+            edge,
+            focused,
+            &is_pressed,
+            &mut pressing,
+            &mut nav_cmds,
+        );
         let command_mapping = mapping! {
-            input_mapping.action_button => Action,
             input_mapping.cancel_button => Cancel,
             input_mapping.left_button => Move(Direction::West),
             input_mapping.right_button => Move(Direction::East),
@@ -197,18 +273,34 @@ pub fn default_gamepad_input(
 /// when integrating in the game) in this case, you should write your own
 /// system that sends [`NavRequest`] events.
 pub fn default_keyboard_input(
-    has_focused: Query<(), With<Focused>>,
+    mut commands: Commands,
+    has_focused: Query<Entity, With<Focused>>,
+    is_pressed: Query<(), With<Pressed>>,
     keyboard: Res<ButtonInput<KeyCode>>,
     input_mapping: Res<InputMapping>,
     mut nav_cmds: EventWriter<NavRequest>,
+    mut pressing: Local<Option<Entity>>,
 ) {
     use Direction::*;
     use NavRequest::*;
 
-    if has_focused.is_empty() {
+    let Ok(focused) = has_focused.single() else {
         // Do not compute navigation if there is no focus to change
         return;
-    }
+    };
+    let action = input_mapping.key_action;
+    track_press(
+        &mut commands,
+        (
+            keyboard.just_pressed(action),
+            keyboard.just_released(action),
+        ),
+        input_mapping.action_edge.unwrap_or(ActionEdge::Press),
+        focused,
+        &is_pressed,
+        &mut pressing,
+        &mut nav_cmds,
+    );
 
     let with_movement = mapping! {
         input_mapping.key_up => Move(North),
@@ -221,7 +313,6 @@ pub fn default_keyboard_input(
         input_mapping.key_right_alt => Move(East)
     };
     let without_movement = mapping! {
-        input_mapping.key_action => Action,
         input_mapping.key_cancel => Cancel,
         input_mapping.key_next => ScopeMove(ScopeDirection::Next),
         input_mapping.key_next_alt => ScopeMove(ScopeDirection::Next),
@@ -254,12 +345,26 @@ pub struct NodePosQuery<'w, 's, T: Component> {
         ),
     >,
     boundaries: Option<Res<'w, ScreenBoundaries>>,
+    hidden: HiddenQueries<'w, 's>,
+    grid: Option<Res<'w, FocusableGrid<T>>>,
 }
 impl<T: Component> NodePosQuery<'_, '_, T> {
     fn cursor_pos(&self, at: Vec2) -> Option<Vec2> {
         let boundaries = self.boundaries.as_ref()?;
         Some(at * boundaries.scale + boundaries.position)
     }
+    /// The focusables that may be at `at`,
+    /// all of them if there is no [`FocusableGrid`] for `T`.
+    fn candidates_at(
+        &self,
+        at: Vec2,
+    ) -> impl Iterator<Item = (Entity, &T, &GlobalTransform, &Focusable)> {
+        let in_grid = self.grid.as_ref().map(|grid| grid.at(at));
+        let in_grid = in_grid.into_iter().flatten();
+        let all = self.grid.is_none().then(|| self.entities.iter());
+        let in_grid = in_grid.filter_map(|e| self.entities.get(e).ok());
+        in_grid.chain(all.into_iter().flatten())
+    }
 }
 
 fn is_in_node<T: ScreenSize>(
@@ -282,13 +387,23 @@ where
 {
     let world_at = query.cursor_pos(at)?;
     query
-        .entities
-        .iter()
+        .candidates_at(world_at)
         .filter(|query_elem| is_in_node(world_at, query_elem))
         .max_by_key(|elem| FloatOrd(elem.2.translation().z))
         .map(|elem| elem.0)
 }
 
+/// The topmost non-blocked [`Focusable`] at world position `at`.
+fn focusable_under<T: ScreenSize + Component>(at: Vec2, query: &NodePosQuery<T>) -> Option<Entity> {
+    query
+        .candidates_at(at)
+        .filter(|query_elem| query_elem.3.state() != FocusState::Blocked)
+        .filter(|query_elem| !query.hidden.is_hidden(query_elem.0))
+        .filter(|query_elem| is_in_node(at, query_elem))
+        .max_by_key(|elem| FloatOrd(elem.2.translation().z))
+        .map(|elem| elem.0)
+}
+
 fn cursor_pos(window: &Window) -> Option<Vec2> {
     window.physical_cursor_position()
 }
@@ -322,22 +437,32 @@ impl ScreenSize for ComputedNode {
 /// [`ui_focusable_at`] to tell which focusable is currently being hovered.
 #[allow(clippy::too_many_arguments)]
 pub fn default_mouse_input(
+    commands: Commands,
     input_mapping: Res<InputMapping>,
     windows: Query<&Window, With<PrimaryWindow>>,
     mouse: Res<ButtonInput<MouseButton>>,
     focusables: NodePosQuery<ComputedNode>,
-    focused: Query<Entity, With<Focused>>,
+    focused: Query<(Entity, Has<Focusable>), With<Focused>>,
+    is_pressed: Query<(), With<Pressed>>,
     nav_cmds: EventWriter<NavRequest>,
     last_pos: Local<Vec2>,
+    pressing: Local<Option<Entity>>,
+    diagnostics: NavDiagnostics,
+    reported: Local<EntityHashSet>,
 ) {
     generic_default_mouse_input(
+        commands,
         input_mapping,
         windows,
         mouse,
         focusables,
         focused,
+        is_pressed,
         nav_cmds,
         last_pos,
+        pressing,
+        diagnostics,
+        reported,
     );
 }
 
@@ -349,38 +474,59 @@ pub fn default_mouse_input(
 /// Which button to press to cause an action event is specified in the
 /// [`InputMapping`] resource.
 ///
+/// Pressing the mouse button on a focusable marks it as [`Pressed`],
+/// moving the cursor out of it cancels the press.
+///
+/// A [`Focused`] entity without [`Focusable`] is reported once
+/// as a [`NavDiagnostic::MissingFocusable`].
+///
 /// You may however need to customize the behavior of this system (typically
 /// when integrating in the game) in this case, you should write your own
 /// system that sends [`NavRequest`] events. You may use
 /// [`ui_focusable_at`] to tell which focusable is currently being hovered.
 #[allow(clippy::too_many_arguments)]
 pub fn generic_default_mouse_input<T: ScreenSize + Component>(
+    mut commands: Commands,
     input_mapping: Res<InputMapping>,
     primary_window: Query<&Window, With<PrimaryWindow>>,
     mouse: Res<ButtonInput<MouseButton>>,
     focusables: NodePosQuery<T>,
-    focused: Query<Entity, With<Focused>>,
+    focused: Query<(Entity, Has<Focusable>), With<Focused>>,
+    is_pressed: Query<(), With<Pressed>>,
     mut nav_cmds: EventWriter<NavRequest>,
     mut last_pos: Local<Vec2>,
+    mut pressing: Local<Option<Entity>>,
+    mut diagnostics: NavDiagnostics,
+    mut reported: Local<EntityHashSet>,
 ) {
-    let no_focusable_msg = "Entity with `Focused` component must also have a `Focusable` component";
-    let Ok(window) = primary_window.single() else {
-        return;
-    };
-    let cursor_pos = match cursor_pos(window) {
-        Some(c) => c,
-        None => return,
-    };
-    let world_cursor_pos = match focusables.cursor_pos(cursor_pos) {
-        Some(c) => c,
-        None => return,
-    };
+    let just_pressed = mouse.just_pressed(input_mapping.mouse_action);
     let released = mouse.just_released(input_mapping.mouse_action);
     let pressed = mouse.pressed(input_mapping.mouse_action);
-    let focused = focused.single();
+    let edge = input_mapping.action_edge.unwrap_or(ActionEdge::Release);
+
+    let cursor_pos = primary_window.single().ok().and_then(cursor_pos);
+    let world_cursor_pos = cursor_pos.and_then(|c| focusables.cursor_pos(c));
+    let (Some(cursor_pos), Some(world_cursor_pos)) = (cursor_pos, world_cursor_pos) else {
+        // A press released outside of the window is cancelled.
+        if let Some(pressed_entity) = pressing.take().filter(|_| released) {
+            commands.entity(pressed_entity).try_remove::<Pressed>();
+        }
+        return;
+    };
+    let focused = focused.single().ok();
+    if let Some((entity, false)) = focused {
+        if reported.insert(entity) {
+            diagnostics.report(NavDiagnostic::MissingFocusable(entity));
+        }
+    }
+    let focused = focused.map(|(entity, _)| entity);
+    reported.retain(|entity| Some(*entity) == focused);
 
     // Return early if cursor didn't move since last call
-    let camera_moved = focusables.boundaries.is_some_and(|b| b.is_changed());
+    let camera_moved = focusables
+        .boundaries
+        .as_ref()
+        .is_some_and(|b| b.is_changed());
     let mouse_moved = *last_pos != cursor_pos;
     if (!released && !pressed) && !mouse_moved && !camera_moved {
         return;
@@ -391,32 +537,164 @@ pub fn generic_default_mouse_input<T: ScreenSize + Component>(
     let pressed = input_mapping.focus_follows_mouse || pressed;
 
     let hovering_focused = |focused| {
-        let focused = focusables.entities.get(focused).expect(no_focusable_msg);
-        is_in_node(world_cursor_pos, &focused)
+        (focusables.entities.get(focused))
+            .is_ok_and(|focused| is_in_node(world_cursor_pos, &focused))
     };
     // If the currently hovered node is the focused one, there is no need to
     // find which node we are hovering and to switch focus to it (since we are
     // already focused on it)
-    let hovering = focused.is_ok_and(hovering_focused);
+    let hovering = focused.is_some_and(hovering_focused);
     let set_focused = (pressed || released) && !hovering;
+    let mut under_mouse = focused.filter(|_| hovering);
     if set_focused {
-        // We only run this code when we really need it because we iterate over all
-        // focusables, which can eat a lot of CPU.
-        let under_mouse = focusables
-            .entities
-            .iter()
-            .filter(|query_elem| query_elem.3.state() != FocusState::Blocked)
-            .filter(|query_elem| is_in_node(world_cursor_pos, query_elem))
-            .max_by_key(|elem| FloatOrd(elem.2.translation().z))
-            .map(|elem| elem.0);
-        let to_target = match under_mouse {
-            Some(c) => c,
-            None => return,
+        // We only run this code when we really need it because without a
+        // `FocusableGrid`, we iterate over all focusables, which can eat a lot of CPU.
+        under_mouse = focusable_under(world_cursor_pos, &focusables);
+        if let Some(to_target) = under_mouse {
+            nav_cmds.write(NavRequest::FocusOn(to_target));
+        }
+    }
+    if let Some(target) = under_mouse.filter(|_| just_pressed) {
+        commands.entity(target).try_insert(Pressed);
+        *pressing = Some(target);
+        if edge == ActionEdge::Press {
+            nav_cmds.write(NavRequest::Action);
+        }
+    }
+    if let Some(pressed_entity) = *pressing {
+        let is_over = |e| {
+            focusables
+                .entities
+                .get(e)
+                .is_ok_and(|e| is_in_node(world_cursor_pos, &e))
         };
-        nav_cmds.write(NavRequest::FocusOn(to_target));
+        let over = is_over(pressed_entity);
+        if released || !over {
+            commands.entity(pressed_entity).try_remove::<Pressed>();
+            *pressing = None;
+        }
+        let still_pressed = just_pressed || is_pressed.contains(pressed_entity);
+        if released && over && still_pressed && edge == ActionEdge::Release {
+            nav_cmds.write(NavRequest::Action);
+        }
+    }
+}
+
+/// Remove [`Pressed`] from [`Focusable`]s that lost focus, cancelling the press.
+#[allow(clippy::type_complexity)]
+pub fn cancel_unfocused_press(
+    mut commands: Commands,
+    pressed: Query<(Entity, &Focusable), (With<Pressed>, Changed<Focusable>)>,
+) {
+    for (entity, focusable) in &pressed {
+        if focusable.state() != FocusState::Focused {
+            commands.entity(entity).try_remove::<Pressed>();
+        }
+    }
+}
+
+/// The [`Focusable`] currently under the mouse cursor.
+///
+/// Unlike [`Focused`], hovering a focusable doesn't change focus
+/// (unless [`InputMapping::focus_follows_mouse`] is set),
+/// so you can highlight hovered elements without stealing focus
+/// from a keyboard or gamepad selected element.
+///
+/// This marker is maintained by [`default_mouse_hover`].
+/// There is at most one `Hovered` entity. It is updated when the cursor
+/// or the camera moves, and when a [`Focusable`] changes, so a focusable
+/// that becomes [`FocusState::Blocked`] loses `Hovered` the next time
+/// the hover system runs.
+#[derive(Component)]
+#[non_exhaustive]
+pub struct Hovered;
+
+/// A system to keep the [`Hovered`] marker on the `bevy_ui` [`Focusable`]
+/// under the mouse cursor.
+///
+/// This is [`generic_default_mouse_hover`] for [`ComputedNode`].
+pub fn default_mouse_hover(
+    commands: Commands,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    focusables: NodePosQuery<ComputedNode>,
+    hovered: Query<Entity, With<Hovered>>,
+    changed: Query<(), Changed<Focusable>>,
+    last_pos: Local<Option<Vec2>>,
+) {
+    generic_default_mouse_hover(commands, windows, focusables, hovered, changed, last_pos);
+}
+
+/// A generic system to keep the [`Hovered`] marker on the [`Focusable`]
+/// under the mouse cursor.
+///
+/// `T` must be a component assigned to `Focusable` elements that implements
+/// the [`ScreenSize`] trait.
+///
+/// Hover is only updated when the cursor or the camera moves,
+/// or when a [`Focusable`] changes, for example when it becomes blocked.
+pub fn generic_default_mouse_hover<T: ScreenSize + Component>(
+    mut commands: Commands,
+    primary_window: Query<&Window, With<PrimaryWindow>>,
+    focusables: NodePosQuery<T>,
+    hovered: Query<Entity, With<Hovered>>,
+    changed: Query<(), Changed<Focusable>>,
+    mut last_pos: Local<Option<Vec2>>,
+) {
+    let cursor_pos = primary_window.single().ok().and_then(cursor_pos);
+    let camera_moved = focusables
+        .boundaries
+        .as_ref()
+        .is_some_and(|b| b.is_changed());
+    if *last_pos == cursor_pos && !camera_moved && changed.is_empty() {
+        return;
+    }
+    *last_pos = cursor_pos;
+
+    let world_cursor_pos = cursor_pos.and_then(|c| focusables.cursor_pos(c));
+    let under_mouse = world_cursor_pos.and_then(|at| focusable_under(at, &focusables));
+    for entity in &hovered {
+        if Some(entity) != under_mouse {
+            commands.entity(entity).try_remove::<Hovered>();
+        }
     }
-    if released && (set_focused || hovering) {
-        nav_cmds.write(NavRequest::Action);
+    if let Some(entity) = under_mouse.filter(|e| !hovered.contains(*e)) {
+        commands.entity(entity).try_insert(Hovered);
+    }
+}
+
+/// Reflect the focus state of [`Focusable`]s in their `bevy_ui` [`Interaction`].
+///
+/// This lets widgets styled by reading [`Interaction`] react to
+/// keyboard and gamepad navigation. The mapping is:
+/// - [`Pressed`] focusables are [`Interaction::Pressed`]
+/// - [`FocusState::Focused`] and [`Hovered`] focusables are [`Interaction::Hovered`]
+/// - Other focusables are [`Interaction::None`]
+///
+/// This overwrites the `Interaction` computed by `bevy_ui` for entities with
+/// both a `Focusable` and an `Interaction` component.
+/// It is not part of [`DefaultNavigationSystems`], add it to your app
+/// after the [`NavRequestSystem`] if you want to use it:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_alt_ui_navigation_lite::prelude::*;
+/// use bevy_alt_ui_navigation_lite::systems::sync_interaction;
+/// # let mut app = App::new();
+/// app.add_systems(Update, sync_interaction.after(NavRequestSystem));
+/// ```
+///
+/// [`NavRequestSystem`]: crate::NavRequestSystem
+pub fn sync_interaction(
+    mut focusables: Query<(&Focusable, Has<Pressed>, Has<Hovered>, &mut Interaction)>,
+) {
+    for (focusable, pressed, hovered, mut interaction) in &mut focusables {
+        let focused = focusable.state() == FocusState::Focused;
+        let new_interaction = match () {
+            () if pressed => Interaction::Pressed,
+            () if focused || hovered => Interaction::Hovered,
+            () => Interaction::None,
+        };
+        interaction.set_if_neq(new_interaction);
     }
 }
 
@@ -471,15 +749,251 @@ pub struct DefaultNavigationSystems;
 impl Plugin for DefaultNavigationSystems {
     fn build(&self, app: &mut App) {
         use crate::NavRequestSystem;
-        app.init_resource::<InputMapping>().add_systems(
-            Update,
-            (
-                update_boundaries.before(default_mouse_input),
-                default_mouse_input,
-                default_gamepad_input,
-                default_keyboard_input,
+        app.init_resource::<InputMapping>()
+            .add_systems(
+                Update,
+                (
+                    update_boundaries.before(default_mouse_input),
+                    default_mouse_input,
+                    default_mouse_hover.after(update_boundaries),
+                    default_gamepad_input,
+                    default_keyboard_input,
+                )
+                    .before(NavRequestSystem),
             )
-                .before(NavRequestSystem),
-        );
+            .add_systems(Update, cancel_unfocused_press.after(NavRequestSystem));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::math::DVec2;
+
+    use super::*;
+    use crate::resolve::Rect;
+
+    fn mouse_app() -> App {
+        let mut app = App::new();
+        let boundaries = ScreenBoundaries {
+            position: Vec2::ZERO,
+            screen_edge: Rect {
+                max: Vec2::new(1280.0, 720.0),
+                min: Vec2::ZERO,
+            },
+            scale: 1.0,
+        };
+        app.add_event::<NavRequest>()
+            .add_event::<NavDiagnostic>()
+            .init_resource::<InputMapping>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .insert_resource(boundaries);
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+        app
+    }
+    /// A 50×50 focusable centered at `(x, 50)`.
+    fn spawn_button(app: &mut App, x: f32) -> Entity {
+        let node = ComputedNode {
+            size: Vec2::splat(50.0),
+            ..default()
+        };
+        let transform = GlobalTransform::from_xyz(x, 50.0, 0.0);
+        app.world_mut()
+            .spawn((Focusable::new(), node, transform))
+            .id()
+    }
+    fn move_cursor(app: &mut App, x: f64) {
+        let world = app.world_mut();
+        let mut windows = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
+        let mut window = windows.single_mut(world).unwrap();
+        window.set_physical_cursor_position(Some(DVec2::new(x, 50.0)));
+    }
+    fn requests(app: &mut App) -> Vec<NavRequest> {
+        let mut requests = app.world_mut().resource_mut::<Events<NavRequest>>();
+        requests.drain().collect()
+    }
+    fn is_pressed(app: &App, entity: Entity) -> bool {
+        app.world().entity(entity).contains::<Pressed>()
+    }
+    fn mouse(app: &mut App, update: impl FnOnce(&mut ButtonInput<MouseButton>)) {
+        let mut mouse = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+        mouse.clear();
+        update(&mut mouse);
+    }
+    fn hovered(app: &mut App) -> Vec<Entity> {
+        let world = app.world_mut();
+        let mut hovered = world.query_filtered::<Entity, With<Hovered>>();
+        hovered.iter(world).collect()
+    }
+
+    #[test]
+    fn hover() {
+        let mut app = mouse_app();
+        app.add_systems(Update, generic_default_mouse_hover::<ComputedNode>);
+        let (left, right) = (spawn_button(&mut app, 50.0), spawn_button(&mut app, 150.0));
+
+        move_cursor(&mut app, 40.0);
+        app.update();
+        assert_eq!(hovered(&mut app), [left]);
+
+        move_cursor(&mut app, 160.0);
+        app.update();
+        assert_eq!(hovered(&mut app), [right]);
+
+        // Blocking the hovered focusable without moving the cursor
+        let blocked = Focusable::new().blocked();
+        app.world_mut().entity_mut(right).insert(blocked);
+        app.update();
+        assert_eq!(hovered(&mut app), []);
+
+        app.world_mut().entity_mut(right).insert(Focusable::new());
+        app.update();
+        assert_eq!(hovered(&mut app), [right]);
+
+        move_cursor(&mut app, 300.0);
+        app.update();
+        assert_eq!(hovered(&mut app), []);
+    }
+
+    #[test]
+    fn mouse_press() {
+        use NavRequest::{Action, FocusOn};
+        let mut app = mouse_app();
+        app.add_systems(Update, generic_default_mouse_input::<ComputedNode>);
+        let (left, right) = (spawn_button(&mut app, 50.0), spawn_button(&mut app, 150.0));
+        app.world_mut().entity_mut(left).insert(Focused);
+        move_cursor(&mut app, 40.0);
+        app.update();
+        requests(&mut app);
+
+        // Mouse activates on release by default
+        mouse(&mut app, |m| m.press(MouseButton::Left));
+        app.update();
+        assert!(is_pressed(&app, left));
+        assert_eq!(requests(&mut app), []);
+
+        mouse(&mut app, |m| m.release(MouseButton::Left));
+        app.update();
+        assert!(!is_pressed(&app, left));
+        assert_eq!(requests(&mut app), [Action]);
+
+        // Dragging off the pressed focusable cancels the press
+        mouse(&mut app, |m| m.press(MouseButton::Left));
+        app.update();
+        assert!(is_pressed(&app, left));
+        mouse(&mut app, |_| {});
+        move_cursor(&mut app, 160.0);
+        app.update();
+        assert!(!is_pressed(&app, left));
+        assert_eq!(requests(&mut app), [FocusOn(right)]);
+
+        mouse(&mut app, |m| m.release(MouseButton::Left));
+        app.update();
+        assert!(!requests(&mut app).contains(&Action));
+
+        // Activate on press instead
+        app.world_mut().resource_mut::<InputMapping>().action_edge = Some(ActionEdge::Press);
+        move_cursor(&mut app, 40.0);
+        mouse(&mut app, |m| m.press(MouseButton::Left));
+        app.update();
+        assert!(is_pressed(&app, left));
+        assert_eq!(requests(&mut app), [Action]);
+
+        mouse(&mut app, |m| m.release(MouseButton::Left));
+        app.update();
+        assert!(!is_pressed(&app, left));
+        assert_eq!(requests(&mut app), []);
+    }
+
+    #[test]
+    fn missing_focusable() {
+        let mut app = mouse_app();
+        app.add_systems(Update, generic_default_mouse_input::<ComputedNode>);
+        let diagnostics = |app: &mut App| {
+            let mut events = app.world_mut().resource_mut::<Events<NavDiagnostic>>();
+            events.drain().collect::<Vec<_>>()
+        };
+        // Focusables without a node are never hovered, but aren't an error
+        let nodeless = app.world_mut().spawn((Focusable::new(), Focused)).id();
+        move_cursor(&mut app, 40.0);
+        app.update();
+        assert_eq!(diagnostics(&mut app), []);
+
+        // Removing `Focusable` also removes `Focused`
+        app.world_mut().entity_mut(nodeless).remove::<Focusable>();
+        app.update();
+        assert!(!app.world().entity(nodeless).contains::<Focused>());
+
+        let not_focusable = app.world_mut().spawn(Focused).id();
+        app.update();
+        move_cursor(&mut app, 60.0);
+        app.update();
+        let missing = NavDiagnostic::MissingFocusable(not_focusable);
+        assert_eq!(diagnostics(&mut app), [missing]);
+    }
+
+    #[test]
+    fn keyboard_press() {
+        use NavRequest::Action;
+        let mut app = mouse_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, (default_keyboard_input, cancel_unfocused_press));
+        app.world_mut().resource_mut::<InputMapping>().action_edge = Some(ActionEdge::Release);
+        let button = spawn_button(&mut app, 50.0);
+        app.world_mut().entity_mut(button).insert(Focused);
+        let keyboard = |app: &mut App, update: fn(&mut ButtonInput<KeyCode>)| {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.clear();
+            update(&mut keyboard);
+            app.update();
+        };
+
+        keyboard(&mut app, |k| k.press(KeyCode::Space));
+        assert!(is_pressed(&app, button));
+        assert_eq!(requests(&mut app), []);
+
+        keyboard(&mut app, |k| k.release(KeyCode::Space));
+        assert!(!is_pressed(&app, button));
+        assert_eq!(requests(&mut app), [Action]);
+
+        // Losing focus cancels the press
+        keyboard(&mut app, |k| k.press(KeyCode::Space));
+        assert!(is_pressed(&app, button));
+        let mut unfocused = Focusable::new();
+        unfocused.state = FocusState::Active;
+        app.world_mut().entity_mut(button).insert(unfocused);
+        keyboard(&mut app, |_| {});
+        assert!(!is_pressed(&app, button));
+
+        keyboard(&mut app, |k| k.release(KeyCode::Space));
+        assert_eq!(requests(&mut app), []);
+    }
+
+    #[test]
+    fn interaction_from_focus() {
+        use bevy::ecs::system::RunSystemOnce;
+        let mut world = World::new();
+        let with_state = |state| {
+            let mut focusable = Focusable::new();
+            focusable.state = state;
+            (focusable, Interaction::Pressed)
+        };
+        let inert = world.spawn(with_state(FocusState::Inert)).id();
+        let active = world.spawn(with_state(FocusState::Active)).id();
+        let focused = world.spawn(with_state(FocusState::Focused)).id();
+        let hovered = world.spawn((with_state(FocusState::Inert), Hovered)).id();
+        let pressed = world.spawn((with_state(FocusState::Focused), Pressed)).id();
+        let interactions = |world: &mut World| {
+            world.run_system_once(sync_interaction).unwrap();
+            let interaction = |e| *world.get::<Interaction>(e).unwrap();
+            [inert, active, focused, hovered, pressed].map(interaction)
+        };
+        use Interaction as I;
+        let expected = [I::None, I::None, I::Hovered, I::Hovered, I::Pressed];
+        assert_eq!(interactions(&mut world), expected);
+
+        world.entity_mut(pressed).remove::<Pressed>();
+        world.entity_mut(hovered).remove::<Hovered>();
+        let expected = [I::None, I::None, I::Hovered, I::None, I::Hovered];
+        assert_eq!(interactions(&mut world), expected);
     }
 }